@@ -2,6 +2,7 @@ use crate::windows::admin::AdminWindowState;
 use crate::windows::connection::ConnectionWindowState;
 use crate::windows::friend_info::{FriendInfoWindow, FriendInfoWindowState};
 use crate::windows::friend_requests::FriendRequestsWindowState;
+use crate::windows::new_game::NewGameWindowState;
 use crate::windows::send_friend_request::SendFriendRequestWindowState;
 use crate::windows::AppWindow;
 use serde::{Deserialize, Serialize};
@@ -12,6 +13,7 @@ pub struct WindowsSystem {
     pub connection_window: ConnectionWindowState,
     pub friend_info_window: FriendInfoWindowState,
     pub friend_requests_window: FriendRequestsWindowState,
+    pub new_game_window: NewGameWindowState,
     pub send_friend_request_window: SendFriendRequestWindowState,
 }
 
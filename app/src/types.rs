@@ -1,2 +1,3 @@
+pub mod practice_game;
 pub mod server_url;
 pub mod timeout;
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use wabble_core::game::bag::tile_distribution;
+use wabble_core::game::board::board_move::{BoardMove, BoardMovePart};
+use wabble_core::game::board::coordinates::BoardCoords;
+use wabble_core::game::board::tile::Tile;
+use wabble_core::game::bot::{Bot, BotLevel};
+use wabble_core::game::error::GameResult;
+use wabble_core::game::rules::GameRules;
+use wabble_core::game::state::GameState;
+use wabble_core::game::turn::TurnAction;
+use wabble_core::game::word_graph::WordTrie;
+use wabble_core::types::language::Language;
+
+pub const PLAYER_ID: &str = "player";
+pub const BOT_ID: &str = "bot";
+
+/// The word lists bundled with the app, only these languages can be played offline.
+const WORD_LISTS: [(Language, &str); 1] = [(
+    Language::English,
+    include_str!("../../../data/resources/english_whitelist.txt"),
+)];
+
+/// The languages a practice game can be played in.
+pub fn languages() -> Vec<Language> {
+    WORD_LISTS.iter().map(|(language, _)| *language).collect()
+}
+
+/// The word list of the language, the lists are built the first time a practice game needs them.
+pub fn word_list(language: Language) -> Option<&'static WordTrie> {
+    static WORDS: OnceLock<HashMap<Language, WordTrie>> = OnceLock::new();
+    WORDS
+        .get_or_init(|| {
+            WORD_LISTS
+                .iter()
+                .map(|(language, words)| {
+                    (*language, WordTrie::from_words(*language, words.lines()))
+                })
+                .collect()
+        })
+        .get(&language)
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// An offline game against the computer, the player always moves first.
+pub struct PracticeGame {
+    state: GameState,
+    words: &'static WordTrie,
+    bot: Bot,
+    /// Tiles placed on the board this turn that are not played yet.
+    pending: Vec<BoardMovePart>,
+    /// The rack index of each pending tile.
+    placed: Vec<usize>,
+    selected: Option<usize>,
+    /// The letter a blank stands for when it is placed.
+    pub blank_letter: Tile,
+}
+
+impl PracticeGame {
+    /// The word list has to be in the language of the rules.
+    pub fn new(
+        rules: GameRules,
+        words: &'static WordTrie,
+        level: BotLevel,
+        seed: u64,
+    ) -> GameResult<Self> {
+        let players = vec![PLAYER_ID.to_string(), BOT_ID.to_string()];
+        let state = GameState::new(players, rules, seed)?;
+        Ok(Self {
+            state,
+            words,
+            bot: Bot::new(level, seed),
+            pending: Vec::new(),
+            placed: Vec::new(),
+            selected: None,
+            blank_letter: Tile::A,
+        })
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    pub fn pending(&self) -> &[BoardMovePart] {
+        &self.pending
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// The letters a blank can stand for.
+    pub fn blank_letters(&self) -> impl Iterator<Item = Tile> {
+        tile_distribution(self.state.rules().language)
+            .iter()
+            .map(|(tile, _)| *tile)
+            .filter(Tile::is_letter)
+    }
+
+    /// The player's rack tiles that are not placed on the board, with their rack index.
+    pub fn rack(&self) -> Vec<(usize, Tile)> {
+        self.state
+            .rack(0)
+            .map(|rack| rack.tiles())
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(index, _)| !self.placed.contains(index))
+            .collect()
+    }
+
+    pub fn select(&mut self, index: usize) {
+        self.selected = if self.selected == Some(index) {
+            None
+        } else {
+            Some(index)
+        };
+    }
+
+    /// Places the selected tile on the square, or takes a pending tile back.
+    pub fn click(&mut self, coords: BoardCoords) {
+        if let Some(position) = self.pending.iter().position(|part| part.coords == coords) {
+            self.pending.remove(position);
+            self.placed.remove(position);
+            return;
+        }
+
+        let Some(index) = self.selected.take() else {
+            return;
+        };
+        if self.state.board().has_tile(&coords) {
+            return;
+        }
+
+        let Some(tile) = self.state.rack(0).and_then(|rack| rack.tiles().get(index)) else {
+            return;
+        };
+        let part = if *tile == Tile::Blank {
+            BoardMovePart::blank(coords, self.blank_letter)
+        } else {
+            BoardMovePart::new(coords, *tile)
+        };
+        self.pending.push(part);
+        self.placed.push(index);
+    }
+
+    pub fn recall(&mut self) {
+        self.pending.clear();
+        self.placed.clear();
+        self.selected = None;
+    }
+
+    pub fn play(&mut self) -> GameResult<()> {
+        let board_move = BoardMove::new(self.pending.clone());
+        self.apply(TurnAction::Place(board_move))
+    }
+
+    pub fn pass(&mut self) -> GameResult<()> {
+        self.apply(TurnAction::Pass)
+    }
+
+    /// Exchanges the whole rack.
+    pub fn exchange(&mut self) -> GameResult<()> {
+        let tiles = self
+            .state
+            .rack(0)
+            .map(|rack| rack.tiles().to_vec())
+            .unwrap_or_default();
+        self.apply(TurnAction::Exchange(tiles))
+    }
+
+    pub fn resign(&mut self) -> GameResult<()> {
+        self.apply(TurnAction::Resign)
+    }
+
+    fn apply(&mut self, action: TurnAction) -> GameResult<()> {
+        self.state.apply(PLAYER_ID, action, self.words, now_ms())?;
+        self.recall();
+
+        if !self.state.is_finished() && self.state.current_player_id() == BOT_ID {
+            self.bot.play(&mut self.state, self.words, now_ms())?;
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,20 @@
+use crate::types::practice_game::PracticeGame;
 use crate::views::View;
+use crate::widgets::board::{WabbleBoard, WabbleBoardUiState};
 use crate::widgets::friend_list::FriendList;
 use crate::windows::admin::AdminWindow;
 use crate::windows::connection::ConnectionWindow;
 use crate::windows::friend_requests::FriendRequestsWindow;
+use crate::windows::new_game::NewGameWindow;
 use crate::windows::profile::{ProfileWindow, ProfileWindowState};
 use crate::windows::send_friend_request::SendFriendRequestWindow;
 use crate::windows::{AppWindow, ToggleableWindow};
 use crate::WabbleApp;
-use egui::{Context, SidePanel, TopBottomPanel, Widget};
+use egui::{Button, CentralPanel, ComboBox, Context, SidePanel, TopBottomPanel, Widget};
 use egui_phosphor::regular;
 use serde::{Deserialize, Serialize};
+use wabble_core::game::error::GameResult;
+use wabble_core::game::state::GameStatus;
 
 #[derive(Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RightPanelTab {
@@ -21,6 +26,9 @@ pub enum RightPanelTab {
 pub struct MainView {
     profile_window: ProfileWindowState,
     right_panel_tab: RightPanelTab,
+    #[serde(skip)]
+    practice_game: Option<PracticeGame>,
+    board_ui: WabbleBoardUiState,
 }
 
 impl MainView {
@@ -30,6 +38,10 @@ impl MainView {
 
             ui.separator();
 
+            NewGameWindow::new(&mut app.windows.new_game_window, &mut self.practice_game)
+                .toggle_button(ui)
+                .show(ui.ctx());
+
             ConnectionWindow::new(&mut app.ws, &mut app.windows.connection_window)
                 .toggle_button(ui)
                 .show(ui.ctx());
@@ -77,6 +89,107 @@ impl MainView {
 
         FriendList::new(&app.ws.store().friends, &mut app.windows.friend_info_window).ui(ui);
     }
+
+    fn show_game(&mut self, app: &mut WabbleApp, ui: &mut egui::Ui) {
+        let Some(game) = &mut self.practice_game else {
+            ui.centered_and_justified(|ui| {
+                ui.label(format!(
+                    "Start a practice game against the computer with {}",
+                    regular::PLUS_CIRCLE
+                ));
+            });
+            return;
+        };
+
+        let state = game.state();
+        let status = match state.status() {
+            GameStatus::Ongoing => "Your turn".to_string(),
+            GameStatus::Finished(_) => match state.winner() {
+                Some(0) => "You won".to_string(),
+                Some(_) => "The computer won".to_string(),
+                None => "Draw".to_string(),
+            },
+        };
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "You {} : {} Computer",
+                state.score(0).unwrap_or_default(),
+                state.score(1).unwrap_or_default()
+            ));
+            ui.separator();
+            ui.label(status);
+            ui.separator();
+            ui.label(format!("{} tiles in the bag", state.bag().remaining()));
+        });
+
+        let is_ongoing = !state.is_finished();
+        let mut result: GameResult<()> = Ok(());
+        ui.horizontal(|ui| {
+            for (index, tile) in game.rack() {
+                let label = tile.as_str().unwrap_or("?").to_string();
+                if ui
+                    .add_enabled(
+                        is_ongoing,
+                        Button::selectable(game.selected() == Some(index), label),
+                    )
+                    .clicked()
+                {
+                    game.select(index);
+                }
+            }
+
+            ui.separator();
+
+            let letters: Vec<_> = game.blank_letters().collect();
+            ComboBox::from_id_salt("practice_game_blank_letter")
+                .selected_text(game.blank_letter.as_str().unwrap_or("?").to_string())
+                .show_ui(ui, |ui| {
+                    for letter in letters {
+                        let label = letter.as_str().unwrap_or("?").to_string();
+                        ui.selectable_value(&mut game.blank_letter, letter, label);
+                    }
+                })
+                .response
+                .on_hover_text("The letter a blank stands for");
+
+            ui.separator();
+
+            ui.add_enabled_ui(is_ongoing, |ui| {
+                if ui.button("Play").clicked() {
+                    result = game.play();
+                }
+                if ui.button("Recall").clicked() {
+                    game.recall();
+                }
+                if ui.button("Pass").clicked() {
+                    result = game.pass();
+                }
+                if ui.button("Exchange").clicked() {
+                    result = game.exchange();
+                }
+                if ui.button("Resign").clicked() {
+                    result = game.resign();
+                }
+            });
+        });
+
+        if let Err(err) = result {
+            app.toasts.error(err.to_string());
+        }
+
+        ui.separator();
+
+        let mut clicked = None;
+        WabbleBoard::new(game.state().board(), &mut self.board_ui)
+            .pending(game.pending())
+            .clicked(&mut clicked)
+            .ui(ui);
+        if let Some(coords) = clicked
+            && is_ongoing
+        {
+            game.click(coords);
+        }
+    }
 }
 
 impl View for MainView {
@@ -88,5 +201,9 @@ impl View for MainView {
         SidePanel::right("main_view_right_panel").show(ctx, |ui| {
             self.show_right_panel(app, ui);
         });
+
+        CentralPanel::default().show(ctx, |ui| {
+            self.show_game(app, ui);
+        });
     }
 }
@@ -4,6 +4,7 @@ pub mod friend;
 pub mod friend_info;
 pub mod friend_list;
 pub mod friend_request;
+pub mod language_select;
 pub mod login_register;
 pub mod profile;
 pub mod simple_list;
@@ -1,14 +1,26 @@
-use eframe::emath::{Pos2, Rect};
-use eframe::epaint::{Color32, Vec2};
+use eframe::emath::{Align2, Pos2, Rect};
+use eframe::epaint::{Color32, FontId, Vec2};
 use egui::{Response, Sense, Ui, Widget};
-use log::debug;
 use serde::{Deserialize, Serialize};
+use wabble_core::game::board::board_move::BoardMovePart;
+use wabble_core::game::board::bonus::Bonus;
+use wabble_core::game::board::coordinates::BoardCoords;
+use wabble_core::game::board::Board;
 
 pub struct WabbleBoardStyle {
     pub light_color: Color32,
     pub dark_color: Color32,
     pub light_color_hover: Color32,
     pub dark_color_hover: Color32,
+    pub tile_color: Color32,
+    pub pending_tile_color: Color32,
+    pub tile_text_color: Color32,
+    pub anchor_color: Color32,
+    pub double_letter_color: Color32,
+    pub triple_letter_color: Color32,
+    pub double_word_color: Color32,
+    pub triple_word_color: Color32,
+    pub bonus_text_color: Color32,
 }
 
 impl WabbleBoardStyle {
@@ -17,56 +29,145 @@ impl WabbleBoardStyle {
         dark_color: Color32::from_rgb(181, 136, 99),
         light_color_hover: Color32::from_rgb(255, 255, 150),
         dark_color_hover: Color32::from_rgb(255, 255, 150),
+        tile_color: Color32::from_rgb(250, 240, 215),
+        pending_tile_color: Color32::from_rgb(255, 230, 130),
+        tile_text_color: Color32::from_rgb(40, 30, 20),
+        anchor_color: Color32::from_rgb(230, 170, 190),
+        double_letter_color: Color32::from_rgb(170, 210, 230),
+        triple_letter_color: Color32::from_rgb(70, 140, 200),
+        double_word_color: Color32::from_rgb(230, 170, 190),
+        triple_word_color: Color32::from_rgb(210, 80, 70),
+        bonus_text_color: Color32::from_rgb(255, 255, 255),
     };
+
+    fn bonus_color(&self, bonus: Bonus) -> Option<Color32> {
+        match bonus {
+            Bonus::None => None,
+            Bonus::Anchor => Some(self.anchor_color),
+            Bonus::DL => Some(self.double_letter_color),
+            Bonus::TL => Some(self.triple_letter_color),
+            Bonus::DW => Some(self.double_word_color),
+            Bonus::TW => Some(self.triple_word_color),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct WabbleBoardUiState {
     pub scene_rect: Rect,
+    /// The board size the scene rect was fitted to.
+    #[serde(default)]
+    pub board_size: u8,
 }
 
 impl Default for WabbleBoardUiState {
     fn default() -> Self {
         Self {
             scene_rect: Rect::from_min_size(Pos2::ZERO, Vec2::splat(15.0)),
+            board_size: 15,
+        }
+    }
+}
+
+impl WabbleBoardUiState {
+    fn fit_board(&mut self, board_size: u8) {
+        if self.board_size != board_size {
+            self.scene_rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(board_size as f32));
+            self.board_size = board_size;
         }
     }
 }
 
 pub struct WabbleBoard<'a> {
+    board: &'a Board,
     ui_state: &'a mut WabbleBoardUiState,
-    style: Option<&'a WabbleBoardStyle>,
+    pending: &'a [BoardMovePart],
+    clicked: Option<&'a mut Option<BoardCoords>>,
 }
 
 impl<'a> WabbleBoard<'a> {
-    pub fn new(ui_state: &'a mut WabbleBoardUiState) -> Self {
+    pub fn new(board: &'a Board, ui_state: &'a mut WabbleBoardUiState) -> Self {
         Self {
+            board,
             ui_state,
-            style: None,
+            pending: &[],
+            clicked: None,
         }
     }
 
-    pub fn style(mut self, style: &'a WabbleBoardStyle) -> Self {
-        self.style = Some(style);
+    /// Tiles that are placed but not played yet.
+    pub fn pending(mut self, pending: &'a [BoardMovePart]) -> Self {
+        self.pending = pending;
+        self
+    }
+
+    /// Receives the coordinates of the square that was clicked this frame.
+    pub fn clicked(mut self, clicked: &'a mut Option<BoardCoords>) -> Self {
+        self.clicked = Some(clicked);
         self
     }
 
-    fn get_square_rect(&self, board_rect: Rect, row: usize, col: usize, square_size: f32) -> Rect {
-        let x = board_rect.left() + col as f32 * square_size;
-        let y = board_rect.top() + row as f32 * square_size;
-        Rect::from_min_size(Pos2::new(x, y), Vec2::splat(square_size))
+    fn paint_bonus(ui: &Ui, square_rect: Rect, bonus: Bonus, style: &WabbleBoardStyle) {
+        let Some(color) = style.bonus_color(bonus) else {
+            return;
+        };
+
+        ui.painter().rect_filled(square_rect, 0.0, color);
+        ui.painter().text(
+            square_rect.center(),
+            Align2::CENTER_CENTER,
+            bonus,
+            FontId::proportional(0.35),
+            style.bonus_text_color,
+        );
+    }
+
+    fn paint_tile(
+        ui: &Ui,
+        square_rect: Rect,
+        letters: &str,
+        value: u8,
+        color: Color32,
+        style: &WabbleBoardStyle,
+    ) {
+        // Multi-letter tiles like CH need a smaller font to fit.
+        let letter_size = if letters.chars().count() > 1 {
+            0.4
+        } else {
+            0.6
+        };
+
+        let tile_rect = square_rect.shrink(0.05);
+        ui.painter().rect_filled(tile_rect, 0.0, color);
+        ui.painter().text(
+            tile_rect.center(),
+            Align2::CENTER_CENTER,
+            letters,
+            FontId::proportional(letter_size),
+            style.tile_text_color,
+        );
+        ui.painter().text(
+            tile_rect.right_bottom() - Vec2::new(0.05, 0.0),
+            Align2::RIGHT_BOTTOM,
+            value,
+            FontId::proportional(0.25),
+            style.tile_text_color,
+        );
     }
 }
 
 impl Widget for WabbleBoard<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let style = self.style.unwrap_or(&WabbleBoardStyle::DEFAULT);
+        let style = &WabbleBoardStyle::DEFAULT;
+        let board = self.board;
+        let board_size = board.size();
+        let language = board.language();
+        let mut clicked = None;
+        self.ui_state.fit_board(board_size);
         let scene = egui::Scene::new().zoom_range(2.0..=500.0);
 
-        scene
+        let response = scene
             .show(ui, &mut self.ui_state.scene_rect, |ui| {
-                let board_size = 15;
-
                 for row in 0..board_size {
                     for col in 0..board_size {
                         let square_rect = Rect::from_min_size(
@@ -83,6 +184,12 @@ impl Widget for WabbleBoard<'_> {
 
                         ui.painter().rect_filled(square_rect, 0.0, color);
 
+                        let coords = BoardCoords::from_x_y(col, row, board_size).ok();
+                        let cell = coords.and_then(|coords| board.get_cell(&coords).ok());
+                        if let Some(cell) = cell {
+                            Self::paint_bonus(ui, square_rect, cell.bonus, style);
+                        }
+
                         let response =
                             ui.interact(square_rect, ui.id().with((row, col)), Sense::click());
                         if response.hovered() {
@@ -94,12 +201,33 @@ impl Widget for WabbleBoard<'_> {
                             ui.painter().rect_filled(square_rect, 0.0, hover_color);
                         }
 
+                        if let Some(cell) = cell
+                            && let Ok(letters) = cell.letters()
+                        {
+                            let color = style.tile_color;
+                            Self::paint_tile(ui, square_rect, &letters, cell.value, color, style);
+                        }
+
+                        let pending = self.pending.iter().find(|part| Some(part.coords) == coords);
+                        if let Some(part) = pending
+                            && let Ok(letters) = part.letters()
+                        {
+                            let value = part.value(language);
+                            let color = style.pending_tile_color;
+                            Self::paint_tile(ui, square_rect, &letters, value, color, style);
+                        }
+
                         if response.clicked() {
-                            debug!("Clicked square: ({}, {})", row, col);
+                            clicked = coords;
                         }
                     }
                 }
             })
-            .response
+            .response;
+
+        if let Some(target) = self.clicked {
+            *target = clicked;
+        }
+        response
     }
 }
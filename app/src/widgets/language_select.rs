@@ -0,0 +1,32 @@
+use egui::{ComboBox, Ui, Widget};
+use wabble_core::types::language::Language;
+
+/// Lists the given languages by the name they have in the language itself.
+pub struct LanguageSelect<'a> {
+    value: &'a mut Language,
+    languages: &'a [Language],
+    id_salt: &'a str,
+}
+
+impl<'a> LanguageSelect<'a> {
+    pub fn new(value: &'a mut Language, languages: &'a [Language], id_salt: &'a str) -> Self {
+        Self {
+            value,
+            languages,
+            id_salt,
+        }
+    }
+}
+
+impl Widget for LanguageSelect<'_> {
+    fn ui(self, ui: &mut Ui) -> egui::Response {
+        ComboBox::from_id_salt(self.id_salt)
+            .selected_text(self.value.definition().native_name)
+            .show_ui(ui, |ui| {
+                for language in self.languages {
+                    ui.selectable_value(self.value, *language, language.definition().native_name);
+                }
+            })
+            .response
+    }
+}
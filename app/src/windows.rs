@@ -5,6 +5,7 @@ pub mod admin;
 pub mod connection;
 pub mod friend_info;
 pub mod friend_requests;
+pub mod new_game;
 pub mod profile;
 pub mod send_friend_request;
 
@@ -0,0 +1,129 @@
+use crate::types::practice_game::{self, PracticeGame};
+use crate::widgets::language_select::LanguageSelect;
+use crate::windows::{AppWindow, ToggleableWindow};
+use egui::{Grid, Id, Ui, WidgetText};
+use egui_phosphor::regular;
+use serde::{Deserialize, Serialize};
+use wabble_core::game::board::bonus::SUPPORTED_BOARD_SIZES;
+use wabble_core::game::bot::BotLevel;
+use wabble_core::game::rules::GameRules;
+
+const BOT_LEVELS: [(BotLevel, &str); 3] = [
+    (BotLevel::Beginner, "Beginner"),
+    (BotLevel::Casual, "Casual"),
+    (BotLevel::Strong, "Strong"),
+];
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct NewGameWindowState {
+    is_open: bool,
+    #[serde(skip)]
+    rules: GameRules,
+    #[serde(skip)]
+    bot_level: BotLevel,
+    #[serde(skip)]
+    error: Option<String>,
+}
+
+/// Starts an offline practice game against the computer.
+pub struct NewGameWindow<'a> {
+    state: &'a mut NewGameWindowState,
+    game: &'a mut Option<PracticeGame>,
+}
+
+impl<'a> NewGameWindow<'a> {
+    pub fn new(state: &'a mut NewGameWindowState, game: &'a mut Option<PracticeGame>) -> Self {
+        Self { state, game }
+    }
+
+    fn start(&mut self) {
+        let rules = self.state.rules.clone();
+        let Some(words) = practice_game::word_list(rules.language) else {
+            let name = rules.language.definition().name;
+            self.state.error = Some(format!("There is no {name} word list"));
+            return;
+        };
+
+        let seed = chrono::Utc::now().timestamp_millis() as u64;
+        match PracticeGame::new(rules, words, self.state.bot_level, seed) {
+            Ok(game) => {
+                *self.game = Some(game);
+                self.state.error = None;
+                self.set_open(false);
+            }
+            Err(err) => self.state.error = Some(err.to_string()),
+        }
+    }
+}
+
+impl AppWindow for NewGameWindow<'_> {
+    fn id() -> Id {
+        Id::new("new_game_window")
+    }
+
+    fn title() -> impl Into<WidgetText> {
+        "New game"
+    }
+
+    fn is_open(&self) -> bool {
+        self.state.is_open
+    }
+
+    fn set_open(&mut self, open: bool) {
+        self.state.is_open = open;
+    }
+
+    fn resizable(&self) -> bool {
+        false
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        let state = &mut *self.state;
+        let languages = practice_game::languages();
+        Grid::new("new_game_rules").num_columns(2).show(ui, |ui| {
+            ui.label("Language");
+            ui.add(LanguageSelect::new(
+                &mut state.rules.language,
+                &languages,
+                "new_game_language",
+            ));
+            ui.end_row();
+
+            ui.label("Board");
+            ui.horizontal(|ui| {
+                for size in SUPPORTED_BOARD_SIZES {
+                    ui.selectable_value(
+                        &mut state.rules.board_size,
+                        *size,
+                        format!("{size}x{size}"),
+                    );
+                }
+            });
+            ui.end_row();
+
+            ui.label("Opponent");
+            ui.horizontal(|ui| {
+                for (level, label) in BOT_LEVELS {
+                    ui.selectable_value(&mut state.bot_level, level, label);
+                }
+            });
+            ui.end_row();
+        });
+
+        if let Some(error) = &self.state.error {
+            ui.small(error);
+        }
+
+        ui.separator();
+
+        if ui.button("Start").clicked() {
+            self.start();
+        }
+    }
+}
+
+impl ToggleableWindow for NewGameWindow<'_> {
+    fn toggle_label(&self) -> String {
+        regular::PLUS_CIRCLE.to_string()
+    }
+}
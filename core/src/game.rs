@@ -1,3 +1,18 @@
+pub mod bag;
 pub mod board;
+pub mod bot;
 pub mod error;
+pub mod gcg;
+pub mod generator;
+pub mod language;
+pub mod leave;
+pub mod position;
+pub mod rack;
+pub mod rng;
+pub mod rules;
 pub mod state;
+pub mod team;
+pub mod turn;
+pub mod validator;
+pub mod view;
+pub mod word_graph;
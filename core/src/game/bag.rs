@@ -0,0 +1,156 @@
+use crate::game::board::tile::Tile;
+use crate::game::error::{GameError, GameResult};
+use crate::game::rng::GameRng;
+use crate::game::rules::GameRules;
+use crate::types::language::Language;
+use bincode::{Decode, Encode};
+
+pub fn tile_distribution(language: Language) -> &'static [(Tile, u8)] {
+    language.definition().distribution
+}
+
+/// Tiles are drawn from the end of the bag, the order is determined by the seed.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TileBag {
+    tiles: Vec<Tile>,
+    rng: GameRng,
+}
+
+impl TileBag {
+    pub fn new(rules: &GameRules, seed: u64) -> Self {
+        let tiles = tile_distribution(rules.language)
+            .iter()
+            .flat_map(|(tile, count)| std::iter::repeat_n(*tile, *count as usize))
+            .collect();
+        Self::from_tiles(tiles, seed)
+    }
+
+    pub fn from_tiles(tiles: Vec<Tile>, seed: u64) -> Self {
+        let mut bag = Self::from_ordered_tiles(tiles, seed);
+        bag.shuffle();
+        bag
+    }
+
+    /// Keeps the order of the tiles, the last one is drawn first.
+    /// The seed is only used to shuffle tiles that are put back.
+    pub fn from_ordered_tiles(tiles: Vec<Tile>, seed: u64) -> Self {
+        Self {
+            tiles,
+            rng: GameRng::new(seed),
+        }
+    }
+
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn remaining_of(&self, tile: Tile) -> usize {
+        self.tiles
+            .iter()
+            .filter(|bag_tile| **bag_tile == tile)
+            .count()
+    }
+
+    /// The remaining count of every tile that is still in the bag.
+    pub fn remaining_counts(&self) -> Vec<(Tile, usize)> {
+        let mut counts: Vec<(Tile, usize)> = Vec::new();
+        for tile in &self.tiles {
+            match counts.iter_mut().find(|(counted, _)| counted == tile) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*tile, 1)),
+            }
+        }
+        counts.sort_by_key(|(tile, _)| *tile as u8);
+        counts
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn draw(&mut self) -> Option<Tile> {
+        self.tiles.pop()
+    }
+
+    /// Draws up to `count` tiles, fewer if the bag runs out.
+    pub fn draw_many(&mut self, count: usize) -> Vec<Tile> {
+        let start = self.tiles.len().saturating_sub(count);
+        self.tiles.split_off(start)
+    }
+
+    /// Takes the given tiles out of the bag, counting duplicates.
+    pub fn take(&mut self, tiles: &[Tile]) -> GameResult<()> {
+        let mut remaining = self.tiles.clone();
+        for tile in tiles {
+            let Some(index) = remaining.iter().position(|bag_tile| bag_tile == tile) else {
+                return Err(GameError::TilesNotInBag);
+            };
+            remaining.remove(index);
+        }
+
+        self.tiles = remaining;
+        Ok(())
+    }
+
+    /// Puts the tiles back into the bag and shuffles it, used for exchanges.
+    pub fn return_tiles(&mut self, tiles: impl IntoIterator<Item = Tile>) {
+        self.tiles.extend(tiles);
+        self.shuffle();
+    }
+
+    fn shuffle(&mut self) {
+        self.rng.shuffle(&mut self.tiles);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_shuffles_the_same_bag() {
+        let first = TileBag::new(&GameRules::classic_english(), 7);
+        assert_eq!(first, TileBag::new(&GameRules::classic_english(), 7));
+        assert_ne!(
+            first.tiles,
+            TileBag::new(&GameRules::classic_english(), 8).tiles
+        );
+    }
+
+    #[test]
+    fn the_bag_holds_the_distribution() {
+        let bag = TileBag::new(&GameRules::classic_english(), 7);
+        assert_eq!(bag.remaining(), 100);
+        assert_eq!(bag.remaining_of(Tile::E), 12);
+        assert_eq!(bag.remaining_of(Tile::Q), 1);
+    }
+
+    #[test]
+    fn draw_many_returns_the_rest_when_the_bag_runs_short() {
+        let mut bag = TileBag::from_tiles(vec![Tile::A, Tile::B, Tile::C], 7);
+        let last = bag.tiles[2];
+
+        assert_eq!(bag.draw_many(1), vec![last]);
+        assert_eq!(bag.draw_many(7).len(), 2);
+        assert!(bag.is_empty());
+        assert!(bag.draw_many(1).is_empty());
+        assert_eq!(bag.draw(), None);
+    }
+
+    #[test]
+    fn returned_tiles_are_shuffled_back_in() {
+        let mut bag = TileBag::new(&GameRules::classic_english(), 7);
+        let drawn = bag.draw_many(7);
+        bag.return_tiles(drawn);
+
+        assert_eq!(bag.remaining(), 100);
+        assert_eq!(
+            bag.remaining_counts(),
+            TileBag::new(&GameRules::classic_english(), 7).remaining_counts()
+        );
+    }
+}
@@ -1,9 +1,15 @@
+use crate::game::board::bonus::layout::BonusLayout;
 use crate::game::board::bonus::Bonus;
 use crate::game::board::line::BoardLines;
 use crate::game::error::{GameError, GameResult};
+use crate::game::rules::GameRules;
+use crate::types::language::Language;
 use bincode::{Decode, Encode};
 use cell::Cell;
 use coordinates::BoardCoords;
+use std::collections::HashSet;
+use tile::alphabet::Alphabet;
+use tile::Tile;
 
 pub mod board_move;
 pub mod bonus;
@@ -12,21 +18,25 @@ pub mod coordinates;
 pub mod line;
 pub mod tile;
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct Board {
     cells: Vec<Cell>,
     size: u8,
+    language: Language,
 }
 
 impl Board {
-    pub fn new(size: u8) -> GameResult<Self> {
-        let mut cells = Vec::with_capacity((size * size) as usize);
-        for _ in 0..(size * size) {
-            cells.push(Cell::default());
-        }
+    pub fn new(rules: &GameRules) -> GameResult<Self> {
+        let size = rules.board_size;
+        let cells = vec![Cell::default(); size as usize * size as usize];
 
-        let mut board = Self { cells, size };
-        bonus::generate_bonuses(&mut board)?;
+        let layout = rules.bonus_layout.build(size)?;
+        let mut board = Self {
+            cells,
+            size,
+            language: rules.language,
+        };
+        board.apply_layout(&layout);
 
         Ok(board)
     }
@@ -35,6 +45,16 @@ impl Board {
         self.size
     }
 
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    fn apply_layout(&mut self, layout: &BonusLayout) {
+        for (cell, bonus) in self.cells.iter_mut().zip(layout.bonuses()) {
+            cell.bonus = *bonus;
+        }
+    }
+
     pub fn get_cell(&self, coords: &BoardCoords) -> GameResult<&Cell> {
         self.cells
             .get(coords.as_index())
@@ -55,13 +75,16 @@ impl Board {
             })
     }
 
-    pub fn get_tile(&self, coords: &BoardCoords) -> GameResult<tile::Tile> {
+    pub fn get_tile(&self, coords: &BoardCoords) -> GameResult<Tile> {
         Ok(self.get_cell(coords)?.tile)
     }
 
     pub fn has_tile(&self, coords: &BoardCoords) -> bool {
-        self.get_tile(coords)
-            .is_ok_and(|tile| tile != tile::Tile::Empty)
+        self.get_tile(coords).is_ok_and(|tile| tile != Tile::Empty)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(|cell| cell.tile == Tile::Empty)
     }
 
     pub fn get_bonus(&self, coords: &BoardCoords) -> GameResult<Bonus> {
@@ -146,16 +169,75 @@ impl Board {
         board_move: &board_move::BoardMove,
         lines: &BoardLines,
     ) -> GameResult<()> {
-        if !board_move.contained_in_one_line(lines, self.size) {
-            return Err(GameError::InvalidMove);
+        if board_move.parts.is_empty() {
+            return Err(GameError::MoveIsEmpty);
         }
 
-        if board_move
+        let mut covered = HashSet::with_capacity(board_move.parts.len());
+        for part in &board_move.parts {
+            let (x, y) = part.coords.as_x_y(self.size);
+            if part.tile == Tile::Empty {
+                return Err(GameError::MoveContainsEmptyTile);
+            }
+            if part.tile == Tile::Blank {
+                return Err(GameError::BlankNotAssigned);
+            }
+            if !Alphabet::for_language(self.language).contains(part.tile) {
+                return Err(GameError::TileNotInAlphabet(part.tile));
+            }
+            if !covered.insert(part.coords) {
+                return Err(GameError::MoveHasDuplicateCoordinates { x, y });
+            }
+            if self.has_tile(&part.coords) {
+                return Err(GameError::SquareOccupied { x, y });
+            }
+        }
+
+        if !board_move.is_in_one_row_or_column(self.size) {
+            return Err(GameError::MoveNotInOneLine);
+        }
+
+        if board_move.parts.len() > 1 && !board_move.contained_in_one_line(lines, self.size) {
+            return Err(GameError::MoveHasGaps);
+        }
+
+        if self.is_empty() {
+            self.validate_first_move(board_move, lines)
+        } else {
+            self.validate_connected(board_move)
+        }
+    }
+
+    fn validate_first_move(
+        &self,
+        board_move: &board_move::BoardMove,
+        lines: &BoardLines,
+    ) -> GameResult<()> {
+        if !board_move
             .parts
             .iter()
-            .any(|part| self.has_tile(&part.coords))
+            .any(|part| self.get_bonus(&part.coords) == Ok(Bonus::Anchor))
         {
-            return Err(GameError::InvalidMove);
+            return Err(GameError::FirstMoveMissesAnchor);
+        }
+
+        if lines.is_empty() {
+            return Err(GameError::MoveFormsNoWord);
+        }
+
+        Ok(())
+    }
+
+    fn validate_connected(&self, board_move: &board_move::BoardMove) -> GameResult<()> {
+        let connected = board_move.parts.iter().any(|part| {
+            part.coords
+                .adjacent(self.size)
+                .iter()
+                .any(|coords| self.has_tile(coords))
+        });
+
+        if !connected {
+            return Err(GameError::MoveNotConnected);
         }
 
         Ok(())
@@ -167,7 +249,288 @@ impl Board {
     ) -> GameResult<board_move::BoardMoveEvaluation> {
         let lines = self.affected_lines(board_move);
         self.validate_move(board_move, &lines)?;
+        self.score_lines(board_move, &lines)
+    }
+
+    /// Scores a move without checking whether it can be placed, for moves that are known to be legal.
+    pub(crate) fn score_move(
+        &self,
+        board_move: &board_move::BoardMove,
+    ) -> GameResult<board_move::BoardMoveEvaluation> {
+        let lines = self.affected_lines(board_move);
+        self.score_lines(board_move, &lines)
+    }
+
+    fn score_lines(
+        &self,
+        board_move: &board_move::BoardMove,
+        lines: &BoardLines,
+    ) -> GameResult<board_move::BoardMoveEvaluation> {
+        let mut words = lines.coords(self.size)?;
+        words.sort_by_key(|coords| !board_move.contains_all_parts(coords));
+
+        let mut evaluation = board_move::BoardMoveEvaluation::default();
+        for coords in words {
+            let (word, score) = self.evaluate_word(board_move, &coords)?;
+            evaluation.words.push(word);
+            evaluation.score += score;
+        }
+
+        Ok(evaluation)
+    }
+
+    /// Places the move on the board, recording the value of every placed tile.
+    pub fn place_move(
+        &mut self,
+        board_move: &board_move::BoardMove,
+    ) -> GameResult<board_move::BoardMoveEvaluation> {
+        let evaluation = self.evaluate(board_move)?;
+        self.place_tiles(board_move)?;
+        Ok(evaluation)
+    }
+
+    /// Places the tiles of an already evaluated move without validating it again.
+    pub(crate) fn place_tiles(&mut self, board_move: &board_move::BoardMove) -> GameResult<()> {
+        for part in &board_move.parts {
+            let value = part.value(self.language);
+            let cell = self.get_cell_mut(&part.coords)?;
+            cell.tile = part.tile;
+            cell.value = value;
+            cell.blank = part.blank;
+        }
+        Ok(())
+    }
+
+    /// Takes the tiles of a move back off the board, used for successful challenges.
+    pub(crate) fn remove_tiles(&mut self, board_move: &board_move::BoardMove) -> GameResult<()> {
+        for part in &board_move.parts {
+            let cell = self.get_cell_mut(&part.coords)?;
+            cell.tile = Tile::Empty;
+            cell.value = 0;
+            cell.blank = false;
+        }
+        Ok(())
+    }
+
+    /// Bonuses only count for squares that are covered by the move itself.
+    ///
+    /// The word has to be spelled the way the alphabet splits it, two `L` tiles can not stand in for `LL`.
+    fn evaluate_word(
+        &self,
+        board_move: &board_move::BoardMove,
+        coords: &[BoardCoords],
+    ) -> GameResult<(String, u32)> {
+        let mut word = String::with_capacity(coords.len());
+        let mut tiles = Vec::with_capacity(coords.len());
+        let mut score = 0;
+        let mut word_multiplier = 1;
+
+        for coords in coords {
+            let (tile, letters) = if let Some(part) = board_move.get_part(coords) {
+                let bonus = self.get_bonus(coords)?;
+                score += part.value(self.language) as u32 * bonus.letter_multiplier();
+                word_multiplier *= bonus.word_multiplier();
+                (part.tile, part.letters()?)
+            } else {
+                let cell = self.get_cell(coords)?;
+                score += cell.value as u32;
+                (cell.tile, cell.letters()?)
+            };
+            tiles.push(tile);
+            word.push_str(&letters);
+        }
+
+        if Alphabet::for_language(self.language).word_tiles(&word) != Some(tiles) {
+            return Err(GameError::MisspelledWord(word));
+        }
+
+        Ok((word, score * word_multiplier))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::board_move::{BoardMove, BoardMovePart};
+    use crate::game::board::tile::Tile;
+
+    fn part(x: u8, y: u8, tile: Tile) -> BoardMovePart {
+        BoardMovePart::new(BoardCoords::from_x_y(x, y, 15).unwrap(), tile)
+    }
+
+    fn blank(x: u8, y: u8, tile: Tile) -> BoardMovePart {
+        BoardMovePart::blank(BoardCoords::from_x_y(x, y, 15).unwrap(), tile)
+    }
+
+    fn english_board() -> Board {
+        Board::new(&GameRules::classic_english()).unwrap()
+    }
+
+    /// CAT across the anchor, the squares of the first row carry no bonus.
+    fn board_with_cat() -> Board {
+        let mut board = english_board();
+        let board_move = BoardMove::new(vec![
+            part(6, 7, Tile::C),
+            part(7, 7, Tile::A),
+            part(8, 7, Tile::T),
+        ]);
+        board.place_move(&board_move).unwrap();
+        board
+    }
+
+    fn evaluate(board: &Board, parts: Vec<BoardMovePart>) -> board_move::BoardMoveEvaluation {
+        board.evaluate(&BoardMove::new(parts)).unwrap()
+    }
+
+    #[test]
+    fn first_move_scores_the_word() {
+        let evaluation = evaluate(
+            &english_board(),
+            vec![
+                part(6, 7, Tile::C),
+                part(7, 7, Tile::A),
+                part(8, 7, Tile::T),
+            ],
+        );
+        assert_eq!(evaluation.words, vec!["CAT"]);
+        assert_eq!(evaluation.score, 5);
+    }
+
+    #[test]
+    fn bonuses_only_count_for_new_tiles() {
+        let mut board = board_with_cat();
+
+        // The O lands on a double letter square.
+        let to = BoardMove::new(vec![part(8, 8, Tile::O)]);
+        let evaluation = board.place_move(&to).unwrap();
+        assert_eq!(evaluation.words, vec!["TO"]);
+        assert_eq!(evaluation.score, 3);
+
+        let toe = evaluate(&board, vec![part(8, 9, Tile::E)]);
+        assert_eq!(toe.words, vec!["TOE"]);
+        assert_eq!(toe.score, 3);
+    }
+
+    #[test]
+    fn cross_words_are_scored() {
+        let board = board_with_cat();
+        let evaluation = evaluate(&board, vec![part(6, 8, Tile::A), part(7, 8, Tile::T)]);
+
+        assert_eq!(evaluation.words[0], "AT");
+        let mut cross_words = evaluation.words[1..].to_vec();
+        cross_words.sort();
+        assert_eq!(cross_words, vec!["AT", "CA"]);
+        // AT 2 + 1, CA 3 + 2 and AT 1 + 1, the A is on a double letter square.
+        assert_eq!(evaluation.score, 10);
+    }
+
+    #[test]
+    fn blanks_score_nothing() {
+        let mut board = english_board();
+        let za = BoardMove::new(vec![blank(7, 7, Tile::Z), part(8, 7, Tile::A)]);
+        let evaluation = board.place_move(&za).unwrap();
+        assert_eq!(evaluation.words, vec!["zA"]);
+        assert_eq!(evaluation.score, 1);
+
+        let cell = board.get_cell(&za.parts[0].coords).unwrap();
+        assert!(cell.blank);
+        assert_eq!(cell.value, 0);
+        assert_eq!(cell.letters().as_deref(), Ok("z"));
+    }
+
+    #[test]
+    fn invalid_placements_are_rejected() {
+        let empty = english_board();
+        let cat = board_with_cat();
+        let cases = [
+            (&empty, vec![], GameError::MoveIsEmpty),
+            (
+                &empty,
+                vec![part(7, 7, Tile::Empty), part(8, 7, Tile::A)],
+                GameError::MoveContainsEmptyTile,
+            ),
+            (
+                &empty,
+                vec![part(7, 7, Tile::Blank), part(8, 7, Tile::A)],
+                GameError::BlankNotAssigned,
+            ),
+            (
+                &empty,
+                vec![part(7, 7, Tile::CH), part(8, 7, Tile::A)],
+                GameError::TileNotInAlphabet(Tile::CH),
+            ),
+            (
+                &empty,
+                vec![part(7, 7, Tile::A), part(7, 7, Tile::T)],
+                GameError::MoveHasDuplicateCoordinates { x: 7, y: 7 },
+            ),
+            (
+                &empty,
+                vec![part(7, 7, Tile::A), part(8, 8, Tile::T)],
+                GameError::MoveNotInOneLine,
+            ),
+            (
+                &empty,
+                vec![part(6, 7, Tile::A), part(8, 7, Tile::T)],
+                GameError::MoveHasGaps,
+            ),
+            (
+                &empty,
+                vec![part(0, 0, Tile::A), part(1, 0, Tile::T)],
+                GameError::FirstMoveMissesAnchor,
+            ),
+            (
+                &empty,
+                vec![part(7, 7, Tile::A)],
+                GameError::MoveFormsNoWord,
+            ),
+            (
+                &cat,
+                vec![part(7, 7, Tile::A)],
+                GameError::SquareOccupied { x: 7, y: 7 },
+            ),
+            (
+                &cat,
+                vec![part(0, 0, Tile::A), part(1, 0, Tile::T)],
+                GameError::MoveNotConnected,
+            ),
+        ];
+
+        for (board, parts, error) in cases {
+            assert_eq!(board.evaluate(&BoardMove::new(parts)).unwrap_err(), error);
+        }
+    }
+
+    #[test]
+    fn words_are_spelled_with_multi_letter_tiles() {
+        let board = Board::new(&GameRules::classic(Language::Spanish)).unwrap();
+
+        let calle = BoardMove::new(vec![
+            part(5, 7, Tile::C),
+            part(6, 7, Tile::A),
+            part(7, 7, Tile::LL),
+            part(8, 7, Tile::E),
+        ]);
+        assert_eq!(board.evaluate(&calle).unwrap().words, vec!["CALLE"]);
+
+        // Two L tiles can not stand in for LL.
+        let two_ls = BoardMove::new(vec![
+            part(5, 7, Tile::C),
+            part(6, 7, Tile::A),
+            part(7, 7, Tile::L),
+            part(8, 7, Tile::L),
+            part(9, 7, Tile::E),
+        ]);
+        assert_eq!(
+            board.evaluate(&two_ls).unwrap_err(),
+            GameError::MisspelledWord("CALLE".to_string())
+        );
+    }
 
-        todo!()
+    #[test]
+    fn gaps_filled_by_existing_tiles_are_allowed() {
+        let board = board_with_cat();
+        let evaluation = evaluate(&board, vec![part(5, 7, Tile::S), part(9, 7, Tile::S)]);
+        assert_eq!(evaluation.words, vec!["SCATS"]);
     }
 }
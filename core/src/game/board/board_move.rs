@@ -2,6 +2,9 @@ use crate::game::board::coordinates::BoardCoords;
 use crate::game::board::line::BoardLine;
 use crate::game::board::line::BoardLines;
 use crate::game::board::tile::Tile;
+use crate::game::error::GameResult;
+use crate::game::validator::WordValidator;
+use crate::types::language::Language;
 use bincode::{Decode, Encode};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Encode, Decode)]
@@ -14,6 +17,24 @@ impl BoardMove {
         Self { parts }
     }
 
+    pub fn get_part(&self, coords: &BoardCoords) -> Option<&BoardMovePart> {
+        self.parts.iter().find(|part| part.coords == *coords)
+    }
+
+    pub fn contains_all_parts(&self, coords: &[BoardCoords]) -> bool {
+        self.parts.iter().all(|part| coords.contains(&part.coords))
+    }
+
+    pub fn is_in_one_row_or_column(&self, board_size: u8) -> bool {
+        let Some(first) = self.parts.first() else {
+            return true;
+        };
+
+        let (x, y) = first.coords.as_x_y(board_size);
+        self.parts.iter().all(|part| part.coords.x(board_size) == x)
+            || self.parts.iter().all(|part| part.coords.y(board_size) == y)
+    }
+
     pub fn contained_in_one_line(&self, lines: &BoardLines, board_size: u8) -> bool {
         for line in lines.vertical_lines() {
             if self
@@ -42,17 +63,106 @@ impl BoardMove {
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub struct BoardMovePart {
     pub coords: BoardCoords,
+    /// For blanks this is the letter the player assigned to it.
     pub tile: Tile,
+    pub blank: bool,
 }
 
 impl BoardMovePart {
     pub fn new(coords: BoardCoords, tile: Tile) -> Self {
-        Self { coords, tile }
+        Self {
+            coords,
+            tile,
+            blank: false,
+        }
+    }
+
+    pub fn blank(coords: BoardCoords, tile: Tile) -> Self {
+        Self {
+            coords,
+            tile,
+            blank: true,
+        }
+    }
+
+    /// Uppercase letters are regular tiles, lowercase letters are blanks.
+    ///
+    /// Takes a single symbol of [`split_notation`](crate::game::board::tile::split_notation), like `a` or `CH`.
+    pub fn from_notation(coords: BoardCoords, symbol: &str) -> GameResult<Self> {
+        let tile = Tile::try_from(symbol)?;
+        if symbol.chars().any(char::is_lowercase) {
+            Ok(Self::blank(coords, tile))
+        } else {
+            Ok(Self::new(coords, tile))
+        }
+    }
+
+    /// The tile this part takes from the player's rack.
+    pub fn rack_tile(&self) -> Tile {
+        if self.blank {
+            Tile::Blank
+        } else {
+            self.tile
+        }
+    }
+
+    pub fn value(&self, language: Language) -> u8 {
+        if self.blank {
+            0
+        } else {
+            self.tile.value(language)
+        }
+    }
+
+    pub fn letters(&self) -> GameResult<String> {
+        tile_letters(self.tile, self.blank)
+    }
+
+    pub fn notation(&self) -> GameResult<String> {
+        tile_notation(self.tile, self.blank)
+    }
+}
+
+/// The letters of the tile as they appear in words, blanks are lowercase.
+pub(crate) fn tile_letters(tile: Tile, blank: bool) -> GameResult<String> {
+    let letters = tile.as_str()?;
+    if blank {
+        Ok(letters.to_lowercase())
+    } else {
+        Ok(letters.to_string())
+    }
+}
+
+/// Like [`tile_letters`], but multi-letter tiles are enclosed in brackets.
+pub(crate) fn tile_notation(tile: Tile, blank: bool) -> GameResult<String> {
+    let letters = tile_letters(tile, blank)?;
+    if tile.is_multi_letter() {
+        Ok(format!("[{letters}]"))
+    } else {
+        Ok(letters)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub struct BoardMoveEvaluation {
+    /// The main word comes first, followed by all cross-words. Blanks are lowercase.
     pub words: Vec<String>,
     pub score: u32,
+    /// The words that were rejected by the last [`check_words`](Self::check_words).
+    pub invalid_words: Vec<String>,
+}
+
+impl BoardMoveEvaluation {
+    pub fn check_words(&mut self, validator: &dyn WordValidator) {
+        self.invalid_words = self
+            .words
+            .iter()
+            .filter(|word| !validator.is_valid_word(&word.to_lowercase()))
+            .cloned()
+            .collect();
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.invalid_words.is_empty()
+    }
 }
@@ -1,12 +1,14 @@
-use crate::game::board::coordinates::BoardCoords;
-use crate::game::board::Board;
-use crate::game::error::{GameError, GameResult};
+use crate::game::error::GameError;
 use bincode::{Decode, Encode};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
+pub mod layout;
 mod quadrants;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub const SUPPORTED_BOARD_SIZES: &[u8] = &[11, 15, 21];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
 pub enum Bonus {
     #[default]
     None = 0,
@@ -17,6 +19,24 @@ pub enum Bonus {
     TW = 5,
 }
 
+impl Bonus {
+    pub fn letter_multiplier(&self) -> u32 {
+        match self {
+            Bonus::DL => 2,
+            Bonus::TL => 3,
+            _ => 1,
+        }
+    }
+
+    pub fn word_multiplier(&self) -> u32 {
+        match self {
+            Bonus::DW => 2,
+            Bonus::TW => 3,
+            _ => 1,
+        }
+    }
+}
+
 impl Display for Bonus {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -30,27 +50,18 @@ impl Display for Bonus {
     }
 }
 
-pub fn generate_bonuses(board: &mut Board) -> GameResult<()> {
-    let quadrant = match board.size {
-        15 => Ok(quadrants::QUADRANT_15X15),
-        _ => Err(GameError::UnsupportedBoardSize(board.size)),
-    }?;
+impl FromStr for Bonus {
+    type Err = GameError;
 
-    for (x, y, bonus) in quadrant {
-        let coords = BoardCoords::from_x_y(*x, *y, board.size)?;
-        apply_bonus(board, coords, *bonus)?;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "[]" => Ok(Bonus::None),
+            "XX" => Ok(Bonus::Anchor),
+            "DL" => Ok(Bonus::DL),
+            "DW" => Ok(Bonus::DW),
+            "TL" => Ok(Bonus::TL),
+            "TW" => Ok(Bonus::TW),
+            _ => Err(GameError::InvalidBonus(s.to_string())),
+        }
     }
-
-    Ok(())
-}
-
-fn apply_bonus(board: &mut Board, coords_up_left: BoardCoords, bonus: Bonus) -> GameResult<()> {
-    let coords_up_right = coords_up_left.mirror_vertically(board.size)?;
-    let coords_down_left = coords_up_left.mirror_horizontally(board.size)?;
-    let coords_down_right = coords_up_right.mirror_horizontally(board.size)?;
-    board.get_cell_mut(&coords_up_left)?.bonus = bonus;
-    board.get_cell_mut(&coords_up_right)?.bonus = bonus;
-    board.get_cell_mut(&coords_down_left)?.bonus = bonus;
-    board.get_cell_mut(&coords_down_right)?.bonus = bonus;
-    Ok(())
 }
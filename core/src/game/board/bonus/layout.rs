@@ -0,0 +1,390 @@
+use crate::game::board::bonus::{quadrants, Bonus};
+use crate::game::board::coordinates::BoardCoords;
+use crate::game::error::{GameError, GameResult};
+use crate::game::rng::GameRng;
+use bincode::{Decode, Encode};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Which bonus layout a game is played on, stored with the game rules.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum BonusLayoutKind {
+    /// The mirrored quadrant layout of the board size.
+    #[default]
+    Classic,
+    Random {
+        seed: u64,
+        constraints: RandomLayoutConstraints,
+    },
+    Custom(BonusLayout),
+}
+
+impl BonusLayoutKind {
+    pub fn build(&self, size: u8) -> GameResult<BonusLayout> {
+        let layout = match self {
+            BonusLayoutKind::Classic => BonusLayout::classic(size)?,
+            BonusLayoutKind::Random { seed, constraints } => {
+                BonusLayout::random(size, *seed, constraints)?
+            }
+            BonusLayoutKind::Custom(layout) => layout.clone(),
+        };
+
+        if layout.size != size {
+            return Err(GameError::BonusLayoutSizeMismatch {
+                layout: layout.size,
+                board: size,
+            });
+        }
+
+        Ok(layout)
+    }
+}
+
+/// The number of each bonus a random layout contains, the anchor is always in the center.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct RandomLayoutConstraints {
+    pub double_letters: u16,
+    pub triple_letters: u16,
+    pub double_words: u16,
+    pub triple_words: u16,
+}
+
+impl Default for RandomLayoutConstraints {
+    /// The bonus counts of the classic 15x15 board.
+    fn default() -> Self {
+        Self {
+            double_letters: 24,
+            triple_letters: 12,
+            double_words: 16,
+            triple_words: 8,
+        }
+    }
+}
+
+impl RandomLayoutConstraints {
+    /// The bonus counts of the classic layout of the given size.
+    pub fn classic(size: u8) -> GameResult<Self> {
+        let layout = BonusLayout::classic(size)?;
+        Ok(Self {
+            double_letters: layout.count(Bonus::DL),
+            triple_letters: layout.count(Bonus::TL),
+            double_words: layout.count(Bonus::DW),
+            triple_words: layout.count(Bonus::TW),
+        })
+    }
+}
+
+/// The bonus of every square of a board, row by row.
+///
+/// Every layout has exactly one anchor, the square the first move has to cover.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct BonusLayout {
+    size: u8,
+    bonuses: Vec<Bonus>,
+}
+
+impl BonusLayout {
+    pub fn new(size: u8, bonuses: Vec<Bonus>) -> GameResult<Self> {
+        if size == 0 {
+            return Err(GameError::UnsupportedBoardSize(size));
+        }
+
+        if bonuses.len() != size as usize * size as usize {
+            return Err(GameError::BonusLayoutNotSquare);
+        }
+
+        match bonuses
+            .iter()
+            .filter(|bonus| **bonus == Bonus::Anchor)
+            .count()
+        {
+            0 => return Err(GameError::BonusLayoutWithoutAnchor),
+            1 => {}
+            _ => return Err(GameError::BonusLayoutWithMultipleAnchors),
+        }
+
+        Ok(Self { size, bonuses })
+    }
+
+    /// The upper left quadrant of the board size, mirrored into the other three.
+    pub fn classic(size: u8) -> GameResult<Self> {
+        let quadrant = match size {
+            11 => Ok(quadrants::QUADRANT_11X11),
+            15 => Ok(quadrants::QUADRANT_15X15),
+            21 => Ok(quadrants::QUADRANT_21X21),
+            _ => Err(GameError::UnsupportedBoardSize(size)),
+        }?;
+
+        let mut bonuses = vec![Bonus::None; size as usize * size as usize];
+        for (x, y, bonus) in quadrant {
+            let up_left = BoardCoords::from_x_y(*x, *y, size)?;
+            let up_right = up_left.mirror_vertically(size)?;
+            let down_left = up_left.mirror_horizontally(size)?;
+            let down_right = up_right.mirror_horizontally(size)?;
+            for coords in [up_left, up_right, down_left, down_right] {
+                bonuses[coords.as_index()] = *bonus;
+            }
+        }
+
+        Self::new(size, bonuses)
+    }
+
+    /// Randomly distributes the bonuses, no two triple words will be next to each other.
+    pub fn random(size: u8, seed: u64, constraints: &RandomLayoutConstraints) -> GameResult<Self> {
+        if size == 0 {
+            return Err(GameError::UnsupportedBoardSize(size));
+        }
+
+        let mut bonuses = vec![Bonus::None; size as usize * size as usize];
+        let center = BoardCoords::from_x_y(size / 2, size / 2, size)?;
+        bonuses[center.as_index()] = Bonus::Anchor;
+
+        let mut free: Vec<BoardCoords> = (0..bonuses.len() as u16)
+            .map(|index| BoardCoords::from_index(index, size))
+            .collect::<GameResult<_>>()?;
+        free.retain(|coords| *coords != center);
+        GameRng::new(seed).shuffle(&mut free);
+
+        let mut triple_words = 0;
+        free.retain(|coords| {
+            let isolated = coords
+                .adjacent(size)
+                .iter()
+                .all(|adjacent| bonuses[adjacent.as_index()] != Bonus::TW);
+            if triple_words < constraints.triple_words && isolated {
+                bonuses[coords.as_index()] = Bonus::TW;
+                triple_words += 1;
+                return false;
+            }
+            true
+        });
+
+        if triple_words < constraints.triple_words {
+            return Err(GameError::RandomLayoutUnsatisfiable);
+        }
+
+        let mut remaining = free.into_iter();
+        for (bonus, count) in [
+            (Bonus::DW, constraints.double_words),
+            (Bonus::TL, constraints.triple_letters),
+            (Bonus::DL, constraints.double_letters),
+        ] {
+            for _ in 0..count {
+                let coords = remaining
+                    .next()
+                    .ok_or(GameError::RandomLayoutUnsatisfiable)?;
+                bonuses[coords.as_index()] = bonus;
+            }
+        }
+
+        Self::new(size, bonuses)
+    }
+
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    pub fn bonuses(&self) -> &[Bonus] {
+        &self.bonuses
+    }
+
+    pub fn get(&self, coords: &BoardCoords) -> Bonus {
+        self.bonuses
+            .get(coords.as_index())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn count(&self, bonus: Bonus) -> u16 {
+        self.bonuses.iter().filter(|other| **other == bonus).count() as u16
+    }
+}
+
+/// Uses the same notation as the bonus display, one row per line.
+impl Display for BonusLayout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.bonuses.chunks(self.size as usize) {
+            for bonus in row {
+                write!(f, "{bonus}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for BonusLayout {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        let size = u8::try_from(rows.len()).map_err(|_| GameError::BonusLayoutNotSquare)?;
+
+        let mut bonuses = Vec::with_capacity(rows.len() * rows.len());
+        for row in rows {
+            let tokens: Vec<char> = row.chars().collect();
+            if tokens.len() != size as usize * 2 {
+                return Err(GameError::BonusLayoutNotSquare);
+            }
+            for token in tokens.chunks(2) {
+                bonuses.push(Bonus::from_str(&token.iter().collect::<String>())?);
+            }
+        }
+
+        Self::new(size, bonuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::bonus::SUPPORTED_BOARD_SIZES;
+
+    fn bonus(layout: &BonusLayout, x: u8, y: u8) -> Bonus {
+        layout.get(&BoardCoords::from_x_y(x, y, layout.size).unwrap())
+    }
+
+    fn counts(layout: &BonusLayout) -> [u16; 5] {
+        [Bonus::Anchor, Bonus::DL, Bonus::DW, Bonus::TL, Bonus::TW].map(|bonus| layout.count(bonus))
+    }
+
+    #[test]
+    fn classic_bonus_counts_match_the_layouts() {
+        // Anchor, DL, DW, TL, TW
+        assert_eq!(
+            counts(&BonusLayout::classic(11).unwrap()),
+            [1, 12, 12, 8, 4]
+        );
+        assert_eq!(
+            counts(&BonusLayout::classic(15).unwrap()),
+            [1, 24, 16, 12, 8]
+        );
+        assert_eq!(
+            counts(&BonusLayout::classic(21).unwrap()),
+            [1, 40, 20, 32, 8]
+        );
+    }
+
+    #[test]
+    fn classic_layouts_are_symmetric() {
+        for size in SUPPORTED_BOARD_SIZES {
+            let layout = BonusLayout::classic(*size).unwrap();
+            let last = size - 1;
+            for x in 0..*size {
+                for y in 0..*size {
+                    let square = bonus(&layout, x, y);
+                    assert_eq!(square, bonus(&layout, y, x), "({x}, {y})");
+                    assert_eq!(square, bonus(&layout, last - x, y), "({x}, {y})");
+                    assert_eq!(square, bonus(&layout, x, last - y), "({x}, {y})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn the_anchor_is_in_the_centre() {
+        for size in SUPPORTED_BOARD_SIZES {
+            let center = size / 2;
+            let classic = BonusLayout::classic(*size).unwrap();
+            assert_eq!(bonus(&classic, center, center), Bonus::Anchor);
+
+            let constraints = RandomLayoutConstraints::classic(*size).unwrap();
+            let random = BonusLayout::random(*size, 7, &constraints).unwrap();
+            assert_eq!(bonus(&random, center, center), Bonus::Anchor);
+        }
+    }
+
+    #[test]
+    fn other_sizes_have_no_classic_layout() {
+        assert_eq!(
+            BonusLayout::classic(13),
+            Err(GameError::UnsupportedBoardSize(13))
+        );
+    }
+
+    #[test]
+    fn the_same_seed_builds_the_same_random_layout() {
+        let constraints = RandomLayoutConstraints::default();
+        let layout = BonusLayout::random(15, 7, &constraints).unwrap();
+        assert_eq!(layout, BonusLayout::random(15, 7, &constraints).unwrap());
+        assert_ne!(layout, BonusLayout::random(15, 8, &constraints).unwrap());
+    }
+
+    #[test]
+    fn random_layouts_have_the_requested_counts() {
+        let constraints = RandomLayoutConstraints {
+            double_letters: 10,
+            triple_letters: 6,
+            double_words: 4,
+            triple_words: 2,
+        };
+        let layout = BonusLayout::random(11, 7, &constraints).unwrap();
+        assert_eq!(counts(&layout), [1, 10, 4, 6, 2]);
+    }
+
+    #[test]
+    fn triple_words_are_never_adjacent() {
+        for seed in 0..20 {
+            let layout =
+                BonusLayout::random(15, seed, &RandomLayoutConstraints::default()).unwrap();
+            for index in 0..layout.bonuses.len() as u16 {
+                let coords = BoardCoords::from_index(index, layout.size).unwrap();
+                if layout.get(&coords) == Bonus::TW {
+                    assert!(coords
+                        .adjacent(layout.size)
+                        .iter()
+                        .all(|adjacent| layout.get(adjacent) != Bonus::TW));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unsatisfiable_constraints_are_rejected() {
+        let too_many_triple_words = RandomLayoutConstraints {
+            triple_words: 6,
+            ..RandomLayoutConstraints::default()
+        };
+        assert_eq!(
+            BonusLayout::random(3, 7, &too_many_triple_words),
+            Err(GameError::RandomLayoutUnsatisfiable)
+        );
+
+        let too_many_bonuses = RandomLayoutConstraints {
+            double_letters: 30,
+            triple_words: 0,
+            ..RandomLayoutConstraints::default()
+        };
+        assert_eq!(
+            BonusLayout::random(5, 7, &too_many_bonuses),
+            Err(GameError::RandomLayoutUnsatisfiable)
+        );
+    }
+
+    #[test]
+    fn layouts_round_trip_through_their_notation() {
+        let classic = BonusLayout::classic(11).unwrap();
+        assert_eq!(classic.to_string().parse(), Ok(classic));
+
+        let random = BonusLayout::random(15, 7, &RandomLayoutConstraints::default()).unwrap();
+        assert_eq!(random.to_string().parse(), Ok(random));
+    }
+
+    #[test]
+    fn invalid_layouts_are_rejected() {
+        let cases = [
+            ("TW[]\n[][]\n", GameError::BonusLayoutWithoutAnchor),
+            ("XX[]\n[]XX\n", GameError::BonusLayoutWithMultipleAnchors),
+            ("XX[]\n[]\n", GameError::BonusLayoutNotSquare),
+            ("XXQQ\n[][]\n", GameError::InvalidBonus("QQ".to_string())),
+        ];
+
+        for (notation, error) in cases {
+            assert_eq!(notation.parse::<BonusLayout>(), Err(error));
+        }
+    }
+}
@@ -2,6 +2,21 @@
 
 use crate::game::board::bonus::Bonus;
 
+pub static QUADRANT_11X11: &[(u8, u8, Bonus)] = &[
+    (0, 0, Bonus::TW),
+    (4, 0, Bonus::DL),
+    (1, 1, Bonus::DW),
+    (5, 1, Bonus::TL),
+    (2, 2, Bonus::DW),
+    (3, 3, Bonus::TL),
+    (5, 3, Bonus::DL),
+    (0, 4, Bonus::DL),
+    (4, 4, Bonus::DW),
+    (1, 5, Bonus::TL),
+    (3, 5, Bonus::DL),
+    (5, 5, Bonus::Anchor),
+];
+
 pub static QUADRANT_15X15: &[(u8, u8, Bonus)] = &[
     (0, 0, Bonus::TW),
     (3, 0, Bonus::DL),
@@ -22,3 +37,35 @@ pub static QUADRANT_15X15: &[(u8, u8, Bonus)] = &[
     (3, 7, Bonus::DL),
     (7, 7, Bonus::Anchor),
 ];
+
+pub static QUADRANT_21X21: &[(u8, u8, Bonus)] = &[
+    (0, 0, Bonus::TW),
+    (3, 0, Bonus::DL),
+    (10, 0, Bonus::TW),
+    (1, 1, Bonus::DW),
+    (7, 1, Bonus::TL),
+    (2, 2, Bonus::DW),
+    (5, 2, Bonus::TL),
+    (9, 2, Bonus::DL),
+    (0, 3, Bonus::DL),
+    (3, 3, Bonus::TL),
+    (4, 4, Bonus::DW),
+    (6, 4, Bonus::DL),
+    (10, 4, Bonus::DL),
+    (2, 5, Bonus::TL),
+    (5, 5, Bonus::DW),
+    (4, 6, Bonus::DL),
+    (6, 6, Bonus::TL),
+    (8, 6, Bonus::TL),
+    (1, 7, Bonus::TL),
+    (7, 7, Bonus::DW),
+    (10, 7, Bonus::DL),
+    (6, 8, Bonus::TL),
+    (8, 8, Bonus::DL),
+    (2, 9, Bonus::DL),
+    (9, 9, Bonus::DL),
+    (0, 10, Bonus::TW),
+    (4, 10, Bonus::DL),
+    (7, 10, Bonus::DL),
+    (10, 10, Bonus::Anchor),
+];
@@ -1,5 +1,7 @@
+use crate::game::board::board_move::{tile_letters, tile_notation};
 use crate::game::board::bonus::Bonus;
 use crate::game::board::tile::Tile;
+use crate::game::error::GameResult;
 use bincode::{Decode, Encode};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Encode, Decode)]
@@ -7,4 +9,16 @@ pub struct Cell {
     pub tile: Tile,
     pub bonus: Bonus,
     pub value: u8,
+    /// Whether the tile is a blank, the cell's tile is the letter it stands for.
+    pub blank: bool,
+}
+
+impl Cell {
+    pub fn letters(&self) -> GameResult<String> {
+        tile_letters(self.tile, self.blank)
+    }
+
+    pub fn notation(&self) -> GameResult<String> {
+        tile_notation(self.tile, self.blank)
+    }
 }
@@ -1,3 +1,6 @@
+use crate::game::board::coordinates::BoardCoords;
+use crate::game::error::GameResult;
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct BoardLines {
     vertical: Vec<BoardLineVertical>,
@@ -10,21 +13,13 @@ impl BoardLines {
     }
 
     pub fn extend_vertical(&mut self, x: u8, y: u8) {
-        for line in &mut self.vertical {
-            if line.extend_if_possible(x, y) {
-                return;
-            }
-        }
-        self.vertical.push(BoardLineVertical::new(y, y, x));
+        extend_lines(&mut self.vertical, x, y, || BoardLineVertical::new(y, y, x));
     }
 
     pub fn extend_horizontal(&mut self, x: u8, y: u8) {
-        for line in &mut self.horizontal {
-            if line.extend_if_possible(x, y) {
-                return;
-            }
-        }
-        self.horizontal.push(BoardLineHorizontal::new(x, x, y));
+        extend_lines(&mut self.horizontal, x, y, || {
+            BoardLineHorizontal::new(x, x, y)
+        });
     }
 
     pub fn prune_single_lines(&mut self) {
@@ -32,6 +27,10 @@ impl BoardLines {
         self.horizontal.retain(|line| line.length() > 1);
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.vertical.is_empty() && self.horizontal.is_empty()
+    }
+
     pub fn vertical_lines(&self) -> &[impl BoardLine] {
         &self.vertical
     }
@@ -39,12 +38,37 @@ impl BoardLines {
     pub fn horizontal_lines(&self) -> &[impl BoardLine] {
         &self.horizontal
     }
+
+    /// The coordinates of every line, horizontal lines first.
+    pub fn coords(&self, board_size: u8) -> GameResult<Vec<Vec<BoardCoords>>> {
+        let horizontal = self.horizontal.iter().map(|line| line.coords(board_size));
+        let vertical = self.vertical.iter().map(|line| line.coords(board_size));
+        horizontal.chain(vertical).collect()
+    }
+}
+
+/// Extends the first line that borders the given position and merges it with
+/// any line it now touches, so a position can never bridge two separate lines.
+fn extend_lines<L: BoardLine>(lines: &mut Vec<L>, x: u8, y: u8, new_line: impl FnOnce() -> L) {
+    let Some(index) = lines
+        .iter_mut()
+        .position(|line| line.extend_if_possible(x, y))
+    else {
+        lines.push(new_line());
+        return;
+    };
+
+    let mut extended = lines.swap_remove(index);
+    lines.retain(|other| !extended.merge_if_possible(other));
+    lines.push(extended);
 }
 
 pub trait BoardLine {
     fn extend_if_possible(&mut self, x: u8, y: u8) -> bool;
+    fn merge_if_possible(&mut self, other: &Self) -> bool;
     fn length(&self) -> u8;
     fn contains(&self, x: u8, y: u8) -> bool;
+    fn coords(&self, board_size: u8) -> GameResult<Vec<BoardCoords>>;
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -79,6 +103,19 @@ impl BoardLine for BoardLineVertical {
         false
     }
 
+    fn merge_if_possible(&mut self, other: &Self) -> bool {
+        if other.column != self.column
+            || other.start > self.end.saturating_add(1)
+            || other.end.saturating_add(1) < self.start
+        {
+            return false;
+        }
+
+        self.start = self.start.min(other.start);
+        self.end = self.end.max(other.end);
+        true
+    }
+
     fn length(&self) -> u8 {
         self.end.saturating_sub(self.start) + 1
     }
@@ -89,6 +126,12 @@ impl BoardLine for BoardLineVertical {
         };
         y >= self.start && y <= self.end
     }
+
+    fn coords(&self, board_size: u8) -> GameResult<Vec<BoardCoords>> {
+        (self.start..=self.end)
+            .map(|y| BoardCoords::from_x_y(self.column, y, board_size))
+            .collect()
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -123,6 +166,19 @@ impl BoardLine for BoardLineHorizontal {
         false
     }
 
+    fn merge_if_possible(&mut self, other: &Self) -> bool {
+        if other.row != self.row
+            || other.start > self.end.saturating_add(1)
+            || other.end.saturating_add(1) < self.start
+        {
+            return false;
+        }
+
+        self.start = self.start.min(other.start);
+        self.end = self.end.max(other.end);
+        true
+    }
+
     fn length(&self) -> u8 {
         self.end.saturating_sub(self.start) + 1
     }
@@ -133,4 +189,10 @@ impl BoardLine for BoardLineHorizontal {
         };
         x >= self.start && x <= self.end
     }
+
+    fn coords(&self, board_size: u8) -> GameResult<Vec<BoardCoords>> {
+        (self.start..=self.end)
+            .map(|x| BoardCoords::from_x_y(x, self.row, board_size))
+            .collect()
+    }
 }
@@ -1,6 +1,9 @@
 use crate::game::error::{GameError, GameResult};
+use crate::types::language::Language;
 use bincode::{Decode, Encode};
 
+pub mod alphabet;
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub enum Tile {
     #[default]
@@ -35,48 +38,128 @@ pub enum Tile {
     OE = 28,
     UE = 29,
     SZ = 30,
+    /// A blank on the rack or in the bag, once placed it stands for a letter.
+    Blank = 31,
+    CH = 32,
+    LL = 33,
+    RR = 34,
+    /// The Spanish Ñ.
+    NY = 35,
 }
 
-impl TryFrom<Tile> for char {
-    type Error = GameError;
+impl Tile {
+    /// Every tile, ordered by its index.
+    pub const ALL: [Tile; 36] = [
+        Tile::Empty,
+        Tile::A,
+        Tile::B,
+        Tile::C,
+        Tile::D,
+        Tile::E,
+        Tile::F,
+        Tile::G,
+        Tile::H,
+        Tile::I,
+        Tile::J,
+        Tile::K,
+        Tile::L,
+        Tile::M,
+        Tile::N,
+        Tile::O,
+        Tile::P,
+        Tile::Q,
+        Tile::R,
+        Tile::S,
+        Tile::T,
+        Tile::U,
+        Tile::V,
+        Tile::W,
+        Tile::X,
+        Tile::Y,
+        Tile::Z,
+        Tile::AE,
+        Tile::OE,
+        Tile::UE,
+        Tile::SZ,
+        Tile::Blank,
+        Tile::CH,
+        Tile::LL,
+        Tile::RR,
+        Tile::NY,
+    ];
 
-    fn try_from(value: Tile) -> GameResult<Self> {
-        match value {
-            Tile::A => Ok('A'),
-            Tile::B => Ok('B'),
-            Tile::C => Ok('C'),
-            Tile::D => Ok('D'),
-            Tile::E => Ok('E'),
-            Tile::F => Ok('F'),
-            Tile::G => Ok('G'),
-            Tile::H => Ok('H'),
-            Tile::I => Ok('I'),
-            Tile::J => Ok('J'),
-            Tile::K => Ok('K'),
-            Tile::L => Ok('L'),
-            Tile::M => Ok('M'),
-            Tile::N => Ok('N'),
-            Tile::O => Ok('O'),
-            Tile::P => Ok('P'),
-            Tile::Q => Ok('Q'),
-            Tile::R => Ok('R'),
-            Tile::S => Ok('S'),
-            Tile::T => Ok('T'),
-            Tile::U => Ok('U'),
-            Tile::V => Ok('V'),
-            Tile::W => Ok('W'),
-            Tile::X => Ok('X'),
-            Tile::Y => Ok('Y'),
-            Tile::Z => Ok('Z'),
-            Tile::AE => Ok('Ä'),
-            Tile::OE => Ok('Ö'),
-            Tile::UE => Ok('Ü'),
-            Tile::SZ => Ok('ẞ'),
+    pub fn is_letter(&self) -> bool {
+        !matches!(self, Tile::Empty | Tile::Blank)
+    }
+
+    /// Tiles that are not part of the language's alphabet are worth nothing.
+    pub fn value(&self, language: Language) -> u8 {
+        language.definition().alphabet.value(*self)
+    }
+
+    /// The letters on the tile in uppercase, multi-letter tiles like `CH` have more than one.
+    pub fn as_str(&self) -> GameResult<&'static str> {
+        match self {
+            Tile::A => Ok("A"),
+            Tile::B => Ok("B"),
+            Tile::C => Ok("C"),
+            Tile::D => Ok("D"),
+            Tile::E => Ok("E"),
+            Tile::F => Ok("F"),
+            Tile::G => Ok("G"),
+            Tile::H => Ok("H"),
+            Tile::I => Ok("I"),
+            Tile::J => Ok("J"),
+            Tile::K => Ok("K"),
+            Tile::L => Ok("L"),
+            Tile::M => Ok("M"),
+            Tile::N => Ok("N"),
+            Tile::O => Ok("O"),
+            Tile::P => Ok("P"),
+            Tile::Q => Ok("Q"),
+            Tile::R => Ok("R"),
+            Tile::S => Ok("S"),
+            Tile::T => Ok("T"),
+            Tile::U => Ok("U"),
+            Tile::V => Ok("V"),
+            Tile::W => Ok("W"),
+            Tile::X => Ok("X"),
+            Tile::Y => Ok("Y"),
+            Tile::Z => Ok("Z"),
+            Tile::AE => Ok("Ä"),
+            Tile::OE => Ok("Ö"),
+            Tile::UE => Ok("Ü"),
+            Tile::SZ => Ok("ẞ"),
+            Tile::Blank => Ok("?"),
+            Tile::CH => Ok("CH"),
+            Tile::LL => Ok("LL"),
+            Tile::RR => Ok("RR"),
+            Tile::NY => Ok("Ñ"),
             Tile::Empty => Err(GameError::TileIsEmpty),
         }
     }
+
+    pub fn is_multi_letter(&self) -> bool {
+        matches!(self, Tile::CH | Tile::LL | Tile::RR)
+    }
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> GameResult<Self> {
+        Tile::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or(GameError::InvalidTileIndex(value))
+    }
 }
 
+/// Letters are case-insensitive, use [`BoardMovePart::from_notation`] to read lowercase letters as blanks.
+///
+/// Accepts the tiles of every language, use an [`Alphabet`] to check them.
+///
+/// [`BoardMovePart::from_notation`]: crate::game::board::board_move::BoardMovePart::from_notation
 impl TryFrom<char> for Tile {
     type Error = GameError;
 
@@ -112,7 +195,49 @@ impl TryFrom<char> for Tile {
             'ö' | 'Ö' => Ok(Tile::OE),
             'ü' | 'Ü' => Ok(Tile::UE),
             'ß' | 'ẞ' => Ok(Tile::SZ),
+            'ñ' | 'Ñ' => Ok(Tile::NY),
+            '?' => Ok(Tile::Blank),
             _ => Err(GameError::InvalidTileChar(value)),
         }
     }
 }
+
+/// A single character or the letters of a multi-letter tile, case-insensitive.
+impl TryFrom<&str> for Tile {
+    type Error = GameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+        if let (Some(char), None) = (chars.next(), chars.next()) {
+            return Tile::try_from(char);
+        }
+
+        match value.to_uppercase().as_str() {
+            "CH" => Ok(Tile::CH),
+            "LL" => Ok(Tile::LL),
+            "RR" => Ok(Tile::RR),
+            _ => Err(GameError::InvalidTileSymbol(value.to_string())),
+        }
+    }
+}
+
+/// Splits text into tile symbols, multi-letter tiles are enclosed in brackets like `[CH]`.
+///
+/// Every other character is a symbol of its own, the brackets are not part of the symbols.
+pub fn split_notation(text: &str) -> GameResult<Vec<&str>> {
+    let mut symbols = Vec::new();
+    let mut rest = text;
+    while let Some(char) = rest.chars().next() {
+        if char == '[' {
+            let (symbol, remaining) = rest[1..]
+                .split_once(']')
+                .ok_or_else(|| GameError::InvalidTileSymbol(rest.to_string()))?;
+            symbols.push(symbol);
+            rest = remaining;
+        } else {
+            symbols.push(&rest[..char.len_utf8()]);
+            rest = &rest[char.len_utf8()..];
+        }
+    }
+    Ok(symbols)
+}
@@ -0,0 +1,120 @@
+use crate::game::board::tile::Tile;
+use crate::types::language::Language;
+
+/// The letter tiles of a language and their values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alphabet {
+    letters: &'static [(Tile, u8)],
+}
+
+impl Alphabet {
+    pub const fn new(letters: &'static [(Tile, u8)]) -> Self {
+        Self { letters }
+    }
+
+    pub fn for_language(language: Language) -> &'static Alphabet {
+        &language.definition().alphabet
+    }
+
+    pub fn letters(&self) -> impl Iterator<Item = Tile> + '_ {
+        self.letters.iter().map(|(tile, _)| *tile)
+    }
+
+    /// Whether the tile can be played in this language, blanks always can.
+    pub fn contains(&self, tile: Tile) -> bool {
+        tile == Tile::Blank || self.letters().any(|letter| letter == tile)
+    }
+
+    /// Tiles that are not part of the alphabet are worth nothing.
+    pub fn value(&self, tile: Tile) -> u8 {
+        self.letters
+            .iter()
+            .find(|(letter, _)| *letter == tile)
+            .map(|(_, value)| *value)
+            .unwrap_or(0)
+    }
+
+    /// Splits a word into its tiles, `None` if it contains letters outside the alphabet.
+    ///
+    /// The longest matching tile is taken, so in Spanish `chorro` is spelled `CH O RR O`.
+    pub fn word_tiles(&self, word: &str) -> Option<Vec<Tile>> {
+        let mut tiles = Vec::with_capacity(word.len());
+        let mut rest = word;
+        while let Some(char) = rest.chars().next() {
+            let multi_letter = self
+                .letters()
+                .filter(Tile::is_multi_letter)
+                .filter_map(|letter| Some((letter, letter.as_str().ok()?)))
+                .filter(|(_, letters)| {
+                    rest.get(..letters.len())
+                        .is_some_and(|start| start.eq_ignore_ascii_case(letters))
+                })
+                .max_by_key(|(_, letters)| letters.len());
+
+            let (tile, length) = match multi_letter {
+                Some((tile, letters)) => (tile, letters.len()),
+                None => (Tile::try_from(char).ok()?, char.len_utf8()),
+            };
+            if !tile.is_letter() || !self.contains(tile) {
+                return None;
+            }
+
+            tiles.push(tile);
+            rest = &rest[length..];
+        }
+        Some(tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static SPANISH_LIKE: Alphabet = Alphabet::new(&[
+        (Tile::A, 1),
+        (Tile::C, 3),
+        (Tile::CH, 5),
+        (Tile::E, 1),
+        (Tile::H, 4),
+        (Tile::L, 1),
+        (Tile::LL, 8),
+        (Tile::O, 1),
+        (Tile::R, 1),
+        (Tile::RR, 8),
+    ]);
+
+    #[test]
+    fn multi_letter_tiles_take_the_longest_match() {
+        assert_eq!(
+            SPANISH_LIKE.word_tiles("chorro"),
+            Some(vec![Tile::CH, Tile::O, Tile::RR, Tile::O])
+        );
+        assert_eq!(
+            SPANISH_LIKE.word_tiles("CaLLe"),
+            Some(vec![Tile::C, Tile::A, Tile::LL, Tile::E])
+        );
+        assert_eq!(
+            SPANISH_LIKE.word_tiles("hola"),
+            Some(vec![Tile::H, Tile::O, Tile::L, Tile::A])
+        );
+    }
+
+    #[test]
+    fn languages_without_multi_letter_tiles_split_every_letter() {
+        let english = Alphabet::for_language(Language::English);
+        assert_eq!(
+            english.word_tiles("chorro"),
+            Some(vec![Tile::C, Tile::H, Tile::O, Tile::R, Tile::R, Tile::O])
+        );
+    }
+
+    #[test]
+    fn letters_outside_the_alphabet_are_rejected() {
+        assert_eq!(SPANISH_LIKE.word_tiles("cat"), None);
+        assert_eq!(
+            Alphabet::for_language(Language::English).word_tiles("ähre"),
+            None
+        );
+        assert_eq!(SPANISH_LIKE.word_tiles("a?"), None);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::game::board::board_move::BoardMove;
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::error::{GameError, GameResult};
+use crate::game::generator::generate_moves;
+use crate::game::leave::{rank_by_equity, RankedMove};
+use crate::game::rack::Rack;
+use crate::game::rng::GameRng;
+use crate::game::state::GameState;
+use crate::game::turn::{TurnAction, TurnRecord};
+use crate::game::validator::WordValidator;
+use crate::game::word_graph::WordGraph;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum BotLevel {
+    Beginner,
+    #[default]
+    Casual,
+    Strong,
+}
+
+impl BotLevel {
+    pub fn strategy(&self) -> BotStrategy {
+        match self {
+            BotLevel::Beginner => BotStrategy::ScoreLimit {
+                max_score: 15,
+                top_n: 5,
+            },
+            BotLevel::Casual => BotStrategy::RandomTopN(5),
+            BotLevel::Strong => BotStrategy::BestEquity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum BotStrategy {
+    BestScore,
+    /// The best score plus rack leave.
+    BestEquity,
+    /// A random pick among the highest scoring moves.
+    RandomTopN(usize),
+    /// A random pick among the highest scoring moves that stay below the limit.
+    ScoreLimit {
+        max_score: u32,
+        top_n: usize,
+    },
+}
+
+/// A computer opponent, it picks its moves from the legal move list.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Bot {
+    pub strategy: BotStrategy,
+    rng: GameRng,
+}
+
+impl Bot {
+    pub fn new(level: BotLevel, seed: u64) -> Self {
+        Self::with_strategy(level.strategy(), seed)
+    }
+
+    pub fn with_strategy(strategy: BotStrategy, seed: u64) -> Self {
+        Self {
+            strategy,
+            rng: GameRng::new(seed),
+        }
+    }
+
+    /// Picks a move for the player to move, exchanges or passes if there is none.
+    ///
+    /// Pending moves with words that are not in the word graph are challenged.
+    pub fn choose_action<G: WordGraph>(
+        &mut self,
+        state: &GameState,
+        graph: &G,
+    ) -> GameResult<TurnAction> {
+        if state.is_finished() {
+            return Err(GameError::GameIsFinished);
+        }
+
+        if let Some(pending) = state.pending_move() {
+            let alphabet = Alphabet::for_language(state.rules().language);
+            let invalid = pending.evaluation.words.iter().any(|word| {
+                !alphabet
+                    .word_tiles(word)
+                    .is_some_and(|tiles| graph.contains(&tiles))
+            });
+            if invalid {
+                return Ok(TurnAction::Challenge);
+            }
+            if state.bag().is_empty() && state.rack(pending.player).is_some_and(Rack::is_empty) {
+                return Ok(TurnAction::Accept);
+            }
+        }
+
+        let rack = state
+            .rack(state.current_player())
+            .ok_or(GameError::UnknownPlayer)?;
+        let moves = generate_moves(state.board(), rack.tiles(), graph)?;
+
+        let ranked = rank_by_equity(moves, rack.tiles(), state.rules(), state.bag().remaining());
+        if let Some(board_move) = self.pick(ranked) {
+            return Ok(TurnAction::Place(board_move));
+        }
+
+        if !rack.is_empty() && state.bag().remaining() >= rack.capacity() as usize {
+            Ok(TurnAction::Exchange(rack.tiles().to_vec()))
+        } else {
+            Ok(TurnAction::Pass)
+        }
+    }
+
+    /// Chooses and applies the move for the player to move.
+    pub fn play<'a, G: WordGraph + WordValidator>(
+        &mut self,
+        state: &'a mut GameState,
+        graph: &G,
+        timestamp_ms: i64,
+    ) -> GameResult<&'a TurnRecord> {
+        let action = self.choose_action(state, graph)?;
+        let player_id = state.current_player_id().to_string();
+        state.apply(&player_id, action, graph, timestamp_ms)
+    }
+
+    /// Expects the moves ranked by equity.
+    fn pick(&mut self, ranked: Vec<RankedMove>) -> Option<BoardMove> {
+        if self.strategy == BotStrategy::BestEquity {
+            return ranked
+                .into_iter()
+                .next()
+                .map(|ranked| ranked.generated.board_move);
+        }
+
+        let mut scored: Vec<(u32, BoardMove)> = ranked
+            .into_iter()
+            .map(|ranked| (ranked.score, ranked.generated.board_move))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        let top_n = match self.strategy {
+            BotStrategy::BestScore | BotStrategy::BestEquity => 1,
+            BotStrategy::RandomTopN(top_n) => top_n,
+            BotStrategy::ScoreLimit { max_score, top_n } => {
+                // Without a move below the limit the weakest move is played.
+                if scored.iter().any(|(score, _)| *score <= max_score) {
+                    scored.retain(|(score, _)| *score <= max_score);
+                } else if let Some(weakest) = scored.pop() {
+                    scored = vec![weakest];
+                }
+                top_n
+            }
+        };
+
+        let candidates = top_n.max(1).min(scored.len());
+        if candidates == 0 {
+            return None;
+        }
+
+        let index = self.rng.next_below(candidates as u64) as usize;
+        Some(scored.swap_remove(index).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::board_move::BoardMovePart;
+    use crate::game::board::coordinates::BoardCoords;
+    use crate::game::board::tile::Tile;
+    use crate::game::position::Position;
+    use crate::game::rules::{ChallengeMode, GameRules};
+    use crate::game::validator::AcceptAllWords;
+    use crate::game::word_graph::WordTrie;
+    use crate::types::language::Language;
+
+    const WORDS: &[&str] = &[
+        "AA", "AS", "AT", "TA", "ACT", "ACTS", "CAT", "CATS", "SAT", "SCAT", "TACT", "TAS",
+    ];
+
+    const CAT_BOARD: &str = "15/15/15/15/15/15/15/7CAT5/15/15/15/15/15/15/15";
+
+    fn state(racks: &str, bag: &str) -> GameState {
+        state_with(GameRules::classic_english(), racks, bag)
+    }
+
+    fn state_with(rules: GameRules, racks: &str, bag: &str) -> GameState {
+        let position = Position::parse(&format!("{CAT_BOARD} {racks} {bag} 0"), &rules).unwrap();
+        GameState::from_position(vec!["a".to_string(), "b".to_string()], rules, position, 7)
+            .unwrap()
+    }
+
+    /// Plays the tile after CAT, the opponent's move is left pending.
+    fn pending_after_cat(tile: Tile) -> GameState {
+        let rules = GameRules {
+            challenge_mode: ChallengeMode::Single,
+            ..GameRules::classic_english()
+        };
+        let mut state = state_with(rules, "ST/AEIOU", "XYZ");
+        let coords = BoardCoords::from_x_y(10, 7, 15).unwrap();
+        let board_move = BoardMove::new(vec![BoardMovePart::new(coords, tile)]);
+        state
+            .apply("a", TurnAction::Place(board_move), &AcceptAllWords, 0)
+            .unwrap();
+        state
+    }
+
+    fn scores(state: &GameState, trie: &WordTrie) -> Vec<u32> {
+        let rack = state.rack(0).unwrap();
+        generate_moves(state.board(), rack.tiles(), trie)
+            .unwrap()
+            .into_iter()
+            .map(|generated| generated.evaluation.score)
+            .collect()
+    }
+
+    fn placed_score(state: &GameState, action: TurnAction) -> u32 {
+        let TurnAction::Place(board_move) = action else {
+            panic!("Expected a placement, got {action:?}");
+        };
+        state.board().evaluate(&board_move).unwrap().score
+    }
+
+    #[test]
+    fn the_best_score_strategy_plays_the_highest_scoring_move() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let state = state("ST/AEIOU", "XYZ");
+        let best = scores(&state, &trie).into_iter().max().unwrap();
+
+        let mut bot = Bot::with_strategy(BotStrategy::BestScore, 1);
+        let action = bot.choose_action(&state, &trie).unwrap();
+        assert_eq!(placed_score(&state, action), best);
+    }
+
+    #[test]
+    fn the_score_limit_keeps_moves_below_the_limit() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let state = state("ST/AEIOU", "XYZ");
+        let strategy = BotStrategy::ScoreLimit {
+            max_score: 4,
+            top_n: 5,
+        };
+
+        for seed in 0..20 {
+            let action = Bot::with_strategy(strategy, seed)
+                .choose_action(&state, &trie)
+                .unwrap();
+            assert!(placed_score(&state, action) <= 4);
+        }
+    }
+
+    #[test]
+    fn the_score_limit_falls_back_to_the_weakest_move() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let state = state("ST/AEIOU", "XYZ");
+        let weakest = scores(&state, &trie).into_iter().min().unwrap();
+        let strategy = BotStrategy::ScoreLimit {
+            max_score: 0,
+            top_n: 5,
+        };
+
+        let action = Bot::with_strategy(strategy, 1)
+            .choose_action(&state, &trie)
+            .unwrap();
+        assert_eq!(placed_score(&state, action), weakest);
+    }
+
+    #[test]
+    fn bots_without_a_move_exchange_or_pass() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let mut bot = Bot::new(BotLevel::Strong, 1);
+
+        let full_bag = state("VVWW/AEIOU", "EEEEEEE");
+        assert_eq!(
+            bot.choose_action(&full_bag, &trie).unwrap(),
+            TurnAction::Exchange(vec![Tile::V, Tile::V, Tile::W, Tile::W])
+        );
+
+        let short_bag = state("VVWW/AEIOU", "EEEEEE");
+        assert_eq!(
+            bot.choose_action(&short_bag, &trie).unwrap(),
+            TurnAction::Pass
+        );
+    }
+
+    #[test]
+    fn phonies_are_challenged() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let mut bot = Bot::new(BotLevel::Strong, 1);
+
+        let phony = pending_after_cat(Tile::T);
+        assert_eq!(
+            bot.choose_action(&phony, &trie).unwrap(),
+            TurnAction::Challenge
+        );
+
+        let valid = pending_after_cat(Tile::S);
+        assert_ne!(
+            bot.choose_action(&valid, &trie).unwrap(),
+            TurnAction::Challenge
+        );
+    }
+
+    #[test]
+    fn the_same_seed_picks_the_same_moves() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let state = state("AST/AEIOU", "XYZ");
+        let mut first = Bot::new(BotLevel::Casual, 42);
+        let mut second = Bot::new(BotLevel::Casual, 42);
+
+        for _ in 0..10 {
+            assert_eq!(
+                first.choose_action(&state, &trie).unwrap(),
+                second.choose_action(&state, &trie).unwrap()
+            );
+        }
+    }
+}
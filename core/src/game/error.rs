@@ -1,17 +1,105 @@
+use crate::game::board::tile::Tile;
+
 pub type GameResult<T> = Result<T, GameError>;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum GameError {
     #[error("Tile is empty")]
     TileIsEmpty,
+    #[error("Blank tile has no letter assigned")]
+    BlankNotAssigned,
+    #[error("Bonus layout has to be square")]
+    BonusLayoutNotSquare,
+    #[error("Bonus layout of size {layout} does not fit a board of size {board}")]
+    BonusLayoutSizeMismatch { layout: u8, board: u8 },
+    #[error("Bonus layout has more than one anchor")]
+    BonusLayoutWithMultipleAnchors,
+    #[error("Bonus layout has no anchor")]
+    BonusLayoutWithoutAnchor,
+    #[error("Player takes part more than once: {0}")]
+    DuplicatePlayer(String),
+    #[error("The first move has to cover the anchor square")]
+    FirstMoveMissesAnchor,
+    #[error("The game is already finished")]
+    GameIsFinished,
+    #[error("GCG score mismatch in line {line}: expected {expected}, got {actual}")]
+    GcgScoreMismatch {
+        line: usize,
+        expected: i32,
+        actual: i32,
+    },
+    #[error("Invalid bonus: {0}")]
+    InvalidBonus(String),
     #[error("Invalid coordinates index for a board size of {size}: {index}")]
     InvalidCoordinatesIndex { index: u16, size: u8 },
     #[error("Invalid coordinates for a board size of {size}: ({x}, {y})")]
     InvalidCoordinatesXY { x: u8, y: u8, size: u8 },
-    #[error("Invalid move")]
-    InvalidMove,
+    #[error("Invalid GCG in line {line}: {reason}")]
+    InvalidGcg { line: usize, reason: String },
+    #[error("Invalid position: {0}")]
+    InvalidPosition(String),
     #[error("Invalid tile char: {0}")]
     InvalidTileChar(char),
+    #[error("Invalid tile index: {0}")]
+    InvalidTileIndex(u8),
+    #[error("Invalid tile symbol: {0}")]
+    InvalidTileSymbol(String),
+    #[error("Invalid turn index: {0}")]
+    InvalidTurnIndex(usize),
+    #[error("Invalid words: {}", .0.join(", "))]
+    InvalidWords(Vec<String>),
+    #[error("Word is not spelled with the tiles of the alphabet: {0}")]
+    MisspelledWord(String),
+    #[error("Move contains an empty tile")]
+    MoveContainsEmptyTile,
+    #[error("Move does not form a word")]
+    MoveFormsNoWord,
+    #[error("Move places more than one tile on ({x}, {y})")]
+    MoveHasDuplicateCoordinates { x: u8, y: u8 },
+    #[error("Move has gaps that are not filled by existing tiles")]
+    MoveHasGaps,
+    #[error("Move does not place any tiles")]
+    MoveIsEmpty,
+    #[error("Move does not touch any existing tile")]
+    MoveNotConnected,
+    #[error("Move tiles are not in a single row or column")]
+    MoveNotInOneLine,
+    #[error("There is no pending move")]
+    NoPendingMove,
+    #[error("The game is not played in teams")]
+    NotATeamGame,
+    #[error("Not enough tiles in the bag: requested {requested}, remaining {remaining}")]
+    NotEnoughTilesInBag { requested: usize, remaining: usize },
+    #[error("Not your turn")]
+    NotYourTurn,
+    #[error("No tiles to exchange")]
+    NothingToExchange,
+    #[error("Nothing to undo")]
+    NothingToUndo,
+    #[error("The player ran out of time")]
+    OutOfTime,
+    #[error("The pending move has to be challenged or accepted first")]
+    PendingMoveUnresolved,
+    #[error("The player already resigned")]
+    PlayerResigned,
+    #[error("Rack is full")]
+    RackFull,
+    #[error("Random layout constraints can not be satisfied")]
+    RandomLayoutUnsatisfiable,
+    #[error("Replay diverged from the recorded history at turn {0}")]
+    ReplayDiverged(usize),
+    #[error("Square is already occupied: ({x}, {y})")]
+    SquareOccupied { x: u8, y: u8 },
+    #[error("Tile is not part of the alphabet: {0:?}")]
+    TileNotInAlphabet(Tile),
+    #[error("Tiles are not in the bag")]
+    TilesNotInBag,
+    #[error("Tiles are not on the rack")]
+    TilesNotOnRack,
+    #[error("Unknown player")]
+    UnknownPlayer,
     #[error("Unsupported board size: {0}")]
     UnsupportedBoardSize(u8),
+    #[error("Unsupported player count: {0}")]
+    UnsupportedPlayerCount(usize),
 }
@@ -0,0 +1,551 @@
+//! Import and export of games in the GCG annotated game format.
+//!
+//! Horizontal moves are written as row then column (`8D`), vertical moves as column then row (`D8`).
+//! Blanks are lowercase letters, tiles that were already on the board are written as `.`.
+//! Multi-letter tiles are enclosed in brackets, like `[CH]`.
+use crate::game::board::board_move::{tile_notation, BoardMove, BoardMovePart};
+use crate::game::board::coordinates::BoardCoords;
+use crate::game::board::tile::{split_notation, Tile};
+use crate::game::board::Board;
+use crate::game::error::{GameError, GameResult};
+use crate::game::rules::GameRules;
+use crate::game::state::{GameEndReason, GameState, GameStatus, MAX_PLAYERS};
+use crate::game::turn::TurnAction;
+use crate::game::validator::AcceptAllWords;
+use std::collections::HashSet;
+
+/// Serializes the players and the history of the game, including the end of game rack points.
+pub fn export(state: &GameState) -> GameResult<String> {
+    let mut lines = vec!["#character-encoding UTF-8".to_string()];
+    for (index, player_id) in state.players().iter().enumerate() {
+        lines.push(format!("#player{} {player_id} {player_id}", index + 1));
+    }
+
+    let mut board = Board::new(state.rules())?;
+    let mut totals = vec![0i32; state.players().len()];
+    let mut last_place = None;
+    for record in state.history() {
+        let nick = player_nick(state, record.player)?;
+        let rack = tiles_to_string(&record.rack)?;
+        totals[record.player as usize] += record.score;
+        let total = totals[record.player as usize];
+
+        match &record.action {
+            TurnAction::Place(board_move) => {
+                let (position, word) = move_notation(&board, board_move)?;
+                board.place_tiles(board_move)?;
+                lines.push(format!(
+                    ">{nick}: {rack} {position} {word} {:+} {total}",
+                    record.score
+                ));
+                last_place = Some(record);
+            }
+            TurnAction::Pass => lines.push(format!(">{nick}: {rack} - +0 {total}")),
+            TurnAction::Exchange(tiles) => {
+                let exchanged = tiles_to_string(tiles)?;
+                lines.push(format!(">{nick}: {rack} -{exchanged} +0 {total}"));
+            }
+            TurnAction::Resign => lines.push(format!("#note {nick} resigned")),
+            TurnAction::Challenge => {
+                let successful = record
+                    .evaluation
+                    .as_ref()
+                    .is_some_and(|evaluation| !evaluation.is_valid());
+                let bonus = state.rules().challenge_bonus as i32;
+                let Some(challenged) = last_place.filter(|_| successful || bonus > 0) else {
+                    lines.push(format!("#note {nick} challenged unsuccessfully"));
+                    continue;
+                };
+
+                if !successful {
+                    totals[challenged.player as usize] += bonus;
+                    lines.push(format!(
+                        ">{}: {} (challenge) +{bonus} {}",
+                        player_nick(state, challenged.player)?,
+                        tiles_to_string(&challenged.rack)?,
+                        totals[challenged.player as usize]
+                    ));
+                    continue;
+                }
+
+                if let TurnAction::Place(board_move) = &challenged.action {
+                    board.remove_tiles(board_move)?;
+                }
+                totals[challenged.player as usize] -= challenged.score;
+                lines.push(format!(
+                    ">{}: {} -- -{} {}",
+                    player_nick(state, challenged.player)?,
+                    tiles_to_string(&challenged.rack)?,
+                    challenged.score,
+                    totals[challenged.player as usize]
+                ));
+            }
+            TurnAction::Accept => {}
+        }
+    }
+
+    let language = state.rules().language;
+    match state.status() {
+        GameStatus::Finished(GameEndReason::PlayedOut { player }) => {
+            // Shared team racks are written for the first player of the team.
+            let opponents = (0..state.rack_count())
+                .filter(|opponent| state.rack_index(*opponent) != state.rack_index(player));
+            for opponent in opponents {
+                let opponent_rack = state.rack(opponent).ok_or(GameError::UnknownPlayer)?;
+                let value = opponent_rack.value(language) as i32;
+                let tiles = tiles_to_string(opponent_rack.tiles())?;
+
+                totals[player as usize] += value;
+                lines.push(format!(
+                    ">{}:  ({tiles}) +{value} {}",
+                    player_nick(state, player)?,
+                    totals[player as usize]
+                ));
+                totals[opponent as usize] -= value;
+                lines.push(format!(
+                    ">{}: {tiles} ({tiles}) -{value} {}",
+                    player_nick(state, opponent)?,
+                    totals[opponent as usize]
+                ));
+            }
+        }
+        GameStatus::Finished(GameEndReason::ScorelessTurns) => {
+            for player in 0..state.rack_count() {
+                let rack = state.rack(player).ok_or(GameError::UnknownPlayer)?;
+                let value = rack.value(language) as i32;
+                let tiles = tiles_to_string(rack.tiles())?;
+
+                totals[player as usize] -= value;
+                lines.push(format!(
+                    ">{}: {tiles} ({tiles}) -{value} {}",
+                    player_nick(state, player)?,
+                    totals[player as usize]
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    for player in 0..state.player_count() {
+        let penalty = state.overtime_penalty(player);
+        if penalty == 0 {
+            continue;
+        }
+
+        let rack = state.rack(player).ok_or(GameError::UnknownPlayer)?;
+        totals[player as usize] -= penalty;
+        lines.push(format!(
+            ">{}: {} (time) -{penalty} {}",
+            player_nick(state, player)?,
+            tiles_to_string(rack.tiles())?,
+            totals[player as usize]
+        ));
+    }
+
+    lines.push(String::new());
+    Ok(lines.join("\n"))
+}
+
+/// Parses a GCG file and plays its moves with the game engine, checking the score of every move.
+///
+/// End of game rack points follow the engine's rules and are not checked.
+/// The racks are taken from the file and restored from the history when the game is replayed.
+pub fn import(gcg: &str, rules: GameRules) -> GameResult<GameState> {
+    let mut players: [Option<String>; MAX_PLAYERS] = Default::default();
+    let mut state: Option<GameState> = None;
+
+    for (index, line) in gcg.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        let invalid = |reason: &str| GameError::InvalidGcg {
+            line: line_number,
+            reason: reason.to_string(),
+        };
+
+        if let Some(pragma) = line.strip_prefix('#') {
+            let mut tokens = pragma.split_whitespace();
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(pragma), Some(nick), _) if pragma.starts_with("player") => {
+                    let index = pragma["player".len()..]
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|number| number.checked_sub(1))
+                        .filter(|index| *index < MAX_PLAYERS)
+                        .ok_or_else(|| invalid("Unsupported player pragma"))?;
+                    players[index] = Some(nick.to_string());
+                }
+                (Some("note"), Some(nick), Some("resigned")) => {
+                    let state = state
+                        .as_mut()
+                        .ok_or_else(|| invalid("No moves before note"))?;
+                    state.apply(nick, TurnAction::Resign, &AcceptAllWords, 0)?;
+                }
+                (Some("note"), Some(nick), Some("challenged")) => {
+                    let state = state
+                        .as_mut()
+                        .ok_or_else(|| invalid("No moves before note"))?;
+                    state.apply(nick, TurnAction::Challenge, &AcceptAllWords, 0)?;
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let Some(event) = line.strip_prefix('>') else {
+            continue;
+        };
+
+        if state.is_none() {
+            let players = player_list(&players).ok_or_else(|| invalid("Missing player pragmas"))?;
+            state = Some(GameState::new(players, rules.clone(), 0)?);
+        }
+        let Some(state) = state.as_mut() else {
+            continue;
+        };
+
+        let (nick, event) = event
+            .split_once(':')
+            .ok_or_else(|| invalid("Missing player nickname"))?;
+        let player = state.player_index(nick.trim())?;
+        let tokens: Vec<&str> = event.split_whitespace().collect();
+        if tokens.len() < 3 {
+            return Err(invalid("Missing score"));
+        }
+
+        let (tokens, scores) = tokens.split_at(tokens.len() - 2);
+        let score: i32 = scores[0].parse().map_err(|_| invalid("Invalid score"))?;
+
+        let (rack, action) = match tokens {
+            // The bonus for an unsuccessful challenge of the player's move.
+            [_, "(challenge)"] => {
+                let challenged = state.pending_move().map(|pending| pending.player);
+                if challenged != Some(player) {
+                    return Err(invalid("No matching move to challenge"));
+                }
+
+                let bonus = state.rules().challenge_bonus as i32;
+                if bonus != score {
+                    return Err(GameError::GcgScoreMismatch {
+                        line: line_number,
+                        expected: score,
+                        actual: bonus,
+                    });
+                }
+
+                let challenger = state.current_player_id().to_string();
+                state.apply(&challenger, TurnAction::Challenge, &AcceptAllWords, 0)?;
+                continue;
+            }
+            // Time penalties depend on the clocks, which GCG does not record.
+            [_, "(time)"] => continue,
+            // A move that was successfully challenged and taken back.
+            [_, "--"] => {
+                let pending_score = state.pending_move().map(|pending| pending.score);
+                if pending_score != Some(-score) {
+                    return Err(invalid("No matching move to take back"));
+                }
+
+                let challenger = state.current_player_id().to_string();
+                state.apply(
+                    &challenger,
+                    TurnAction::Challenge,
+                    &HashSet::<String>::new(),
+                    0,
+                )?;
+                continue;
+            }
+            // End of game rack points, the engine applies them on its own.
+            [rest] | [_, rest] if rest.starts_with('(') => {
+                if state.pending_move().is_some() {
+                    let opponent = state.current_player_id().to_string();
+                    state.apply(&opponent, TurnAction::Accept, &AcceptAllWords, 0)?;
+                }
+                continue;
+            }
+            [rack, "-"] => (rack, TurnAction::Pass),
+            [rack, exchanged] if exchanged.starts_with('-') => {
+                let tiles =
+                    parse_tiles(&exchanged[1..]).map_err(|_| invalid("Invalid exchange"))?;
+                (rack, TurnAction::Exchange(tiles))
+            }
+            [rack, position, word] => {
+                let board_move = parse_move(state.board(), position, word)
+                    .ok_or_else(|| invalid("Invalid move"))?;
+                (rack, TurnAction::Place(board_move))
+            }
+            _ => return Err(invalid("Unsupported event")),
+        };
+
+        let rack = parse_tiles(rack).map_err(|_| invalid("Invalid rack"))?;
+        state.set_rack(player, &rack)?;
+
+        let record = state.apply(nick.trim(), action, &AcceptAllWords, 0)?;
+        if record.score != score {
+            return Err(GameError::GcgScoreMismatch {
+                line: line_number,
+                expected: score,
+                actual: record.score,
+            });
+        }
+    }
+
+    match state {
+        Some(state) => Ok(state),
+        None => {
+            let players = player_list(&players).ok_or_else(|| GameError::InvalidGcg {
+                line: 0,
+                reason: "Missing player pragmas".to_string(),
+            })?;
+            GameState::new(players, rules, 0)
+        }
+    }
+}
+
+/// The players in turn order, `None` if a player between them is missing.
+fn player_list(players: &[Option<String>]) -> Option<Vec<String>> {
+    let count = players.iter().rposition(Option::is_some)? + 1;
+    players[..count].iter().cloned().collect()
+}
+
+fn player_nick(state: &GameState, player: u8) -> GameResult<&str> {
+    state.player_id(player).ok_or(GameError::UnknownPlayer)
+}
+
+fn tiles_to_string(tiles: &[Tile]) -> GameResult<String> {
+    tiles
+        .iter()
+        .map(|tile| tile_notation(*tile, false))
+        .collect()
+}
+
+fn parse_tiles(tiles: &str) -> GameResult<Vec<Tile>> {
+    split_notation(tiles)?
+        .into_iter()
+        .map(Tile::try_from)
+        .collect()
+}
+
+fn column_letter(x: u8) -> char {
+    (b'A' + x) as char
+}
+
+/// The position and the main word of the move, written before the move is placed on the board.
+fn move_notation(board: &Board, board_move: &BoardMove) -> GameResult<(String, String)> {
+    let size = board.size();
+    let first = board_move
+        .parts
+        .iter()
+        .min_by_key(|part| part.coords.as_index())
+        .ok_or(GameError::MoveIsEmpty)?;
+
+    let horizontal = if board_move.parts.len() > 1 {
+        let y = first.coords.y(size);
+        board_move.parts.iter().all(|part| part.coords.y(size) == y)
+    } else {
+        let has_tile = |coords: Option<BoardCoords>| coords.is_some_and(|c| board.has_tile(&c));
+        has_tile(first.coords.left(size))
+            || has_tile(first.coords.right(size))
+            || !(has_tile(first.coords.top(size)) || has_tile(first.coords.bottom(size)))
+    };
+    let previous = |coords: &BoardCoords| {
+        if horizontal {
+            coords.left(size)
+        } else {
+            coords.top(size)
+        }
+    };
+    let next = |coords: &BoardCoords| {
+        if horizontal {
+            coords.right(size)
+        } else {
+            coords.bottom(size)
+        }
+    };
+
+    let mut start = first.coords;
+    while let Some(coords) = previous(&start).filter(|coords| board.has_tile(coords)) {
+        start = coords;
+    }
+
+    let mut word = String::new();
+    let mut current = Some(start);
+    while let Some(coords) = current {
+        if let Some(part) = board_move.get_part(&coords) {
+            word.push_str(&part.notation()?);
+        } else if board.has_tile(&coords) {
+            word.push('.');
+        } else {
+            break;
+        }
+        current = next(&coords);
+    }
+
+    let (x, y) = start.as_x_y(size);
+    let position = if horizontal {
+        format!("{}{}", y + 1, column_letter(x))
+    } else {
+        format!("{}{}", column_letter(x), y + 1)
+    };
+
+    Ok((position, word))
+}
+
+/// Tiles that were already on the board are written as `.` or enclosed in parentheses.
+fn parse_move(board: &Board, position: &str, word: &str) -> Option<BoardMove> {
+    let size = board.size();
+    let horizontal = position.starts_with(|c: char| c.is_ascii_digit());
+    let digits: String = position.chars().filter(char::is_ascii_digit).collect();
+    let letters: Vec<char> = position.chars().filter(char::is_ascii_alphabetic).collect();
+    let [letter] = letters[..] else {
+        return None;
+    };
+
+    let row: u8 = digits.parse().ok()?;
+    let x = (letter.to_ascii_uppercase() as u8).checked_sub(b'A')?;
+    let y = row.checked_sub(1)?;
+
+    let mut coords = Some(BoardCoords::from_x_y(x, y, size).ok()?);
+    let mut parts = Vec::new();
+    let mut played_through = false;
+    for symbol in split_notation(word).ok()? {
+        match symbol {
+            "(" => {
+                played_through = true;
+                continue;
+            }
+            ")" => {
+                played_through = false;
+                continue;
+            }
+            _ => {}
+        }
+
+        let current = coords?;
+        if symbol != "." && !played_through {
+            parts.push(BoardMovePart::from_notation(current, symbol).ok()?);
+        }
+
+        coords = if horizontal {
+            current.right(size)
+        } else {
+            current.bottom(size)
+        };
+    }
+
+    Some(BoardMove::new(parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rules::ChallengeMode;
+
+    /// A complete game in which Bert plays out.
+    const ADA_VS_BERT: &str = include_str!("../../tests/fixtures/ada_vs_bert.gcg");
+
+    /// The same game with a phony that is taken back and an unsuccessful challenge.
+    const FIVE_POINT_CHALLENGE: &str =
+        include_str!("../../tests/fixtures/five_point_challenge.gcg");
+
+    fn five_point_rules() -> GameRules {
+        GameRules {
+            challenge_mode: ChallengeMode::Single,
+            challenge_bonus: 5,
+            ..GameRules::classic_english()
+        }
+    }
+
+    #[test]
+    fn import_scores_a_finished_game() {
+        let state = import(ADA_VS_BERT, GameRules::classic_english()).unwrap();
+
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::PlayedOut { player: 1 })
+        );
+        assert_eq!(state.score(0), Some(388));
+        assert_eq!(state.score(1), Some(336));
+    }
+
+    #[test]
+    fn export_round_trips_through_import() {
+        let state = import(ADA_VS_BERT, GameRules::classic_english()).unwrap();
+        let exported = export(&state).unwrap();
+
+        let without_description: Vec<&str> = ADA_VS_BERT
+            .lines()
+            .filter(|line| !line.starts_with("#description"))
+            .collect();
+        assert_eq!(exported.lines().collect::<Vec<_>>(), without_description);
+
+        let reimported = import(&exported, GameRules::classic_english()).unwrap();
+        assert_eq!(reimported.history(), state.history());
+        assert_eq!(reimported.status(), state.status());
+        assert_eq!(reimported.score(0), state.score(0));
+        assert_eq!(reimported.score(1), state.score(1));
+    }
+
+    #[test]
+    fn imported_games_can_be_replayed() {
+        let mut state = import(ADA_VS_BERT, GameRules::classic_english()).unwrap();
+
+        let replayed = state.replay(state.history().len()).unwrap();
+        assert_eq!(replayed.history(), state.history());
+        assert_eq!(replayed.status(), state.status());
+        assert_eq!(replayed.score(0), state.score(0));
+
+        let turns = state.history().len();
+        state.undo().unwrap();
+        assert_eq!(state.history().len(), turns - 1);
+        assert_eq!(state.score(1), Some(321));
+    }
+
+    #[test]
+    fn scores_have_to_match_the_engine() {
+        let gcg = ADA_VS_BERT.replace("8H UNAI +4 4", "8H UNAI +5 5");
+        assert_eq!(
+            import(&gcg, GameRules::classic_english()).unwrap_err(),
+            GameError::GcgScoreMismatch {
+                line: 5,
+                expected: 5,
+                actual: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn challenges_round_trip_through_import() {
+        let state = import(FIVE_POINT_CHALLENGE, five_point_rules()).unwrap();
+        assert_eq!(state.score(0), Some(393));
+        assert_eq!(state.score(1), Some(336));
+
+        let exported = export(&state).unwrap();
+        let without_description: Vec<&str> = FIVE_POINT_CHALLENGE
+            .lines()
+            .filter(|line| !line.starts_with("#description"))
+            .collect();
+        assert_eq!(exported.lines().collect::<Vec<_>>(), without_description);
+
+        let replayed = state.replay(state.history().len()).unwrap();
+        assert_eq!(replayed.history(), state.history());
+        assert_eq!(replayed.score(0), state.score(0));
+        assert_eq!(replayed.score(1), state.score(1));
+    }
+
+    #[test]
+    fn challenge_bonus_has_to_match_the_rules() {
+        let rules = GameRules {
+            challenge_mode: ChallengeMode::Single,
+            ..GameRules::classic_english()
+        };
+
+        assert!(matches!(
+            import(FIVE_POINT_CHALLENGE, rules),
+            Err(GameError::GcgScoreMismatch {
+                expected: 5,
+                actual: 0,
+                ..
+            })
+        ));
+    }
+}
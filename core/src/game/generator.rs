@@ -0,0 +1,544 @@
+//! Lists every legal move for a rack, following Appel and Jacobson's algorithm.
+//!
+//! Moves are grown from anchors, the empty squares next to a tile (or the anchor squares on an empty board).
+//! Letters left of an anchor are taken from the rack, then the word is extended to the right through the word graph.
+//! Cross-checks restrict every square to the letters that form valid words with the perpendicular tiles.
+use crate::game::board::board_move::{BoardMove, BoardMoveEvaluation, BoardMovePart};
+use crate::game::board::bonus::Bonus;
+use crate::game::board::coordinates::BoardCoords;
+use crate::game::board::tile::Tile;
+use crate::game::board::Board;
+use crate::game::error::GameResult;
+use crate::game::word_graph::WordGraph;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedMove {
+    pub board_move: BoardMove,
+    pub evaluation: BoardMoveEvaluation,
+}
+
+/// Every legal move for the rack, sorted by score, highest first.
+///
+/// The scores do not include the bingo bonus, it depends on the rules.
+pub fn generate_moves<G: WordGraph>(
+    board: &Board,
+    rack: &[Tile],
+    graph: &G,
+) -> GameResult<Vec<GeneratedMove>> {
+    let mut generator = MoveGenerator::new(board, rack, graph);
+    generator.generate(true)?;
+    generator.generate(false)?;
+
+    let mut moves = generator.moves;
+    moves.sort_by_key(|generated| std::cmp::Reverse(generated.evaluation.score));
+    Ok(moves)
+}
+
+const ALL_TILES: u64 = u64::MAX;
+
+struct MoveGenerator<'a, G: WordGraph> {
+    board: &'a Board,
+    graph: &'a G,
+    size: u8,
+    /// Rack tile counts, indexed by the tile.
+    rack: [u8; Tile::ALL.len()],
+    horizontal: bool,
+    /// Allowed tiles per square for the current direction, as a bit set indexed by the tile.
+    cross_checks: Vec<u64>,
+    placed: Vec<BoardMovePart>,
+    seen: HashSet<BoardMove>,
+    moves: Vec<GeneratedMove>,
+}
+
+impl<'a, G: WordGraph> MoveGenerator<'a, G> {
+    fn new(board: &'a Board, rack: &[Tile], graph: &'a G) -> Self {
+        let mut counts = [0u8; Tile::ALL.len()];
+        for tile in rack {
+            counts[*tile as usize] += 1;
+        }
+
+        Self {
+            board,
+            graph,
+            size: board.size(),
+            rack: counts,
+            horizontal: true,
+            cross_checks: Vec::new(),
+            placed: Vec::new(),
+            seen: HashSet::new(),
+            moves: Vec::new(),
+        }
+    }
+
+    /// The coordinates of a square, lines are rows when generating horizontal moves.
+    fn coords(&self, line: u8, position: u8) -> GameResult<BoardCoords> {
+        if self.horizontal {
+            BoardCoords::from_x_y(position, line, self.size)
+        } else {
+            BoardCoords::from_x_y(line, position, self.size)
+        }
+    }
+
+    fn tile_at(&self, line: u8, position: u8) -> GameResult<Tile> {
+        self.board.get_tile(&self.coords(line, position)?)
+    }
+
+    fn generate(&mut self, horizontal: bool) -> GameResult<()> {
+        self.horizontal = horizontal;
+        self.compute_cross_checks()?;
+
+        let first_move = self.board.is_empty();
+        for line in 0..self.size {
+            for position in 0..self.size {
+                if !self.is_anchor(line, position, first_move)? {
+                    continue;
+                }
+
+                if position > 0 && self.tile_at(line, position - 1)? != Tile::Empty {
+                    let mut start = position - 1;
+                    while start > 0 && self.tile_at(line, start - 1)? != Tile::Empty {
+                        start -= 1;
+                    }
+
+                    let mut prefix = Vec::with_capacity((position - start) as usize);
+                    for x in start..position {
+                        prefix.push(self.tile_at(line, x)?);
+                    }
+
+                    if let Some(node) = self.graph.walk(self.graph.root(), &prefix) {
+                        self.extend_right(node, line, position, position)?;
+                    }
+                } else {
+                    let mut limit = 0;
+                    while limit < position
+                        && !self.is_anchor(line, position - limit - 1, first_move)?
+                    {
+                        limit += 1;
+                    }
+                    self.left_part(self.graph.root(), line, position, limit)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_anchor(&self, line: u8, position: u8, first_move: bool) -> GameResult<bool> {
+        let coords = self.coords(line, position)?;
+        if self.board.has_tile(&coords) {
+            return Ok(false);
+        }
+
+        if first_move {
+            return Ok(self.board.get_bonus(&coords)? == Bonus::Anchor);
+        }
+
+        Ok(coords
+            .adjacent(self.size)
+            .iter()
+            .any(|adjacent| self.board.has_tile(adjacent)))
+    }
+
+    fn compute_cross_checks(&mut self) -> GameResult<()> {
+        let mut cross_checks = vec![ALL_TILES; self.size as usize * self.size as usize];
+        for line in 0..self.size {
+            for position in 0..self.size {
+                let coords = self.coords(line, position)?;
+                if !self.board.has_tile(&coords) {
+                    cross_checks[coords.as_index()] = self.cross_check(line, position)?;
+                }
+            }
+        }
+
+        self.cross_checks = cross_checks;
+        Ok(())
+    }
+
+    /// The tiles that form a word with the tiles above and below, or left and right for vertical moves.
+    fn cross_check(&self, line: u8, position: u8) -> GameResult<u64> {
+        let mut before = Vec::new();
+        let mut current = line;
+        while current > 0 && self.tile_at(current - 1, position)? != Tile::Empty {
+            current -= 1;
+            before.push(self.tile_at(current, position)?);
+        }
+        before.reverse();
+
+        let mut after = Vec::new();
+        let mut current = line;
+        while current + 1 < self.size && self.tile_at(current + 1, position)? != Tile::Empty {
+            current += 1;
+            after.push(self.tile_at(current, position)?);
+        }
+
+        if before.is_empty() && after.is_empty() {
+            return Ok(ALL_TILES);
+        }
+
+        let graph = self.graph;
+        let Some(node) = graph.walk(graph.root(), &before) else {
+            return Ok(0);
+        };
+
+        Ok(graph
+            .children(node)
+            .filter(|(_, child)| {
+                graph
+                    .walk(*child, &after)
+                    .is_some_and(|end| graph.is_word(end))
+            })
+            .fold(0, |mask, (tile, _)| mask | 1 << tile as u64))
+    }
+
+    /// Builds every left part from the rack, up to `limit` squares left of the anchor.
+    fn left_part(&mut self, node: G::Node, line: u8, anchor: u8, limit: u8) -> GameResult<()> {
+        self.extend_right(node, line, anchor, anchor)?;
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let graph = self.graph;
+        for (tile, child) in graph.children(node) {
+            for blank in [false, true] {
+                if !self.take(tile, blank) {
+                    continue;
+                }
+
+                // The left part is shifted one square to the left for every added tile.
+                for part in &mut self.placed {
+                    let (x, y) = part.coords.as_x_y(self.size);
+                    part.coords = if self.horizontal {
+                        BoardCoords::from_x_y(x - 1, y, self.size)?
+                    } else {
+                        BoardCoords::from_x_y(x, y - 1, self.size)?
+                    };
+                }
+                let coords = self.coords(line, anchor - 1)?;
+                self.placed.push(part(coords, tile, blank));
+
+                self.left_part(child, line, anchor, limit - 1)?;
+
+                self.placed.pop();
+                for part in &mut self.placed {
+                    let (x, y) = part.coords.as_x_y(self.size);
+                    part.coords = if self.horizontal {
+                        BoardCoords::from_x_y(x + 1, y, self.size)?
+                    } else {
+                        BoardCoords::from_x_y(x, y + 1, self.size)?
+                    };
+                }
+                self.put_back(tile, blank);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extend_right(
+        &mut self,
+        node: G::Node,
+        line: u8,
+        position: u8,
+        anchor: u8,
+    ) -> GameResult<()> {
+        if position >= self.size {
+            if position > anchor && self.graph.is_word(node) {
+                self.record()?;
+            }
+            return Ok(());
+        }
+
+        let coords = self.coords(line, position)?;
+        let existing = self.board.get_tile(&coords)?;
+        if existing != Tile::Empty {
+            if let Some(child) = self.graph.child(node, existing) {
+                self.extend_right(child, line, position + 1, anchor)?;
+            }
+            return Ok(());
+        }
+
+        if position > anchor && self.graph.is_word(node) {
+            self.record()?;
+        }
+
+        let allowed = self.cross_checks[coords.as_index()];
+        let graph = self.graph;
+        for (tile, child) in graph.children(node) {
+            if allowed & (1 << tile as u64) == 0 {
+                continue;
+            }
+
+            for blank in [false, true] {
+                if !self.take(tile, blank) {
+                    continue;
+                }
+
+                self.placed.push(part(coords, tile, blank));
+                self.extend_right(child, line, position + 1, anchor)?;
+                self.placed.pop();
+                self.put_back(tile, blank);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn take(&mut self, tile: Tile, blank: bool) -> bool {
+        let tile = if blank { Tile::Blank } else { tile };
+        if self.rack[tile as usize] == 0 {
+            return false;
+        }
+        self.rack[tile as usize] -= 1;
+        true
+    }
+
+    fn put_back(&mut self, tile: Tile, blank: bool) {
+        let tile = if blank { Tile::Blank } else { tile };
+        self.rack[tile as usize] += 1;
+    }
+
+    fn record(&mut self) -> GameResult<()> {
+        // Single letter words on an empty board are not legal moves.
+        if self.placed.len() == 1 && self.board.is_empty() {
+            return Ok(());
+        }
+
+        let mut parts = self.placed.clone();
+        parts.sort_by_key(|part| part.coords.as_index());
+        let board_move = BoardMove::new(parts);
+
+        // Single tiles are found in both directions.
+        if !self.seen.insert(board_move.clone()) {
+            return Ok(());
+        }
+
+        let evaluation = self.board.score_move(&board_move)?;
+        self.moves.push(GeneratedMove {
+            board_move,
+            evaluation,
+        });
+        Ok(())
+    }
+}
+
+fn part(coords: BoardCoords, tile: Tile, blank: bool) -> BoardMovePart {
+    if blank {
+        BoardMovePart::blank(coords, tile)
+    } else {
+        BoardMovePart::new(coords, tile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rules::GameRules;
+    use crate::game::validator::WordValidator;
+    use crate::game::word_graph::WordTrie;
+    use crate::types::language::Language;
+
+    const WORDS: &[&str] = &[
+        "AA", "AS", "AT", "TA", "ACT", "ACTS", "CAT", "CATS", "SAT", "SCAT", "TACT", "TAS",
+    ];
+
+    fn coords(x: u8, y: u8) -> BoardCoords {
+        BoardCoords::from_x_y(x, y, 15).unwrap()
+    }
+
+    fn empty_board() -> Board {
+        Board::new(&GameRules::classic_english()).unwrap()
+    }
+
+    /// CAT across the anchor.
+    fn board_with_cat() -> Board {
+        let mut board = empty_board();
+        let cat = BoardMove::new(vec![
+            BoardMovePart::new(coords(6, 7), Tile::C),
+            BoardMovePart::new(coords(7, 7), Tile::A),
+            BoardMovePart::new(coords(8, 7), Tile::T),
+        ]);
+        board.place_move(&cat).unwrap();
+        board
+    }
+
+    fn generated(board: &Board, rack: &[Tile], trie: &WordTrie) -> HashSet<BoardMove> {
+        generate_moves(board, rack, trie)
+            .unwrap()
+            .into_iter()
+            .map(|generated| generated.board_move)
+            .collect()
+    }
+
+    /// Every ordered selection of rack tiles, blanks stand for each of the given letters.
+    fn sequences(rack: &[Tile], letters: &[Tile]) -> Vec<Vec<BoardMovePart>> {
+        let mut all = Vec::new();
+        for (index, tile) in rack.iter().enumerate() {
+            let mut rest = rack.to_vec();
+            rest.remove(index);
+
+            let parts: Vec<BoardMovePart> = if *tile == Tile::Blank {
+                letters
+                    .iter()
+                    .map(|letter| BoardMovePart::blank(coords(0, 0), *letter))
+                    .collect()
+            } else {
+                vec![BoardMovePart::new(coords(0, 0), *tile)]
+            };
+
+            for part in parts {
+                all.push(vec![part]);
+                for mut sequence in sequences(&rest, letters) {
+                    sequence.insert(0, part);
+                    all.push(sequence);
+                }
+            }
+        }
+        all
+    }
+
+    /// Places every sequence on the first empty squares from every empty square, in both directions.
+    fn brute_force(board: &Board, rack: &[Tile], trie: &WordTrie) -> HashSet<BoardMove> {
+        let letters = [Tile::A, Tile::C, Tile::S, Tile::T];
+        let mut moves = HashSet::new();
+
+        for sequence in sequences(rack, &letters) {
+            for horizontal in [true, false] {
+                for line in 0..15 {
+                    for start in 0..15 {
+                        let square = |position: u8| {
+                            if horizontal {
+                                coords(position, line)
+                            } else {
+                                coords(line, position)
+                            }
+                        };
+
+                        let mut parts = Vec::new();
+                        let mut position = start;
+                        for part in &sequence {
+                            while position < 15 && board.has_tile(&square(position)) {
+                                position += 1;
+                            }
+                            if position >= 15 {
+                                break;
+                            }
+                            parts.push(BoardMovePart {
+                                coords: square(position),
+                                ..*part
+                            });
+                            position += 1;
+                        }
+                        if parts.len() != sequence.len() {
+                            continue;
+                        }
+
+                        parts.sort_by_key(|part| part.coords.as_index());
+                        let board_move = BoardMove::new(parts);
+                        let Ok(evaluation) = board.evaluate(&board_move) else {
+                            continue;
+                        };
+                        if evaluation.words.iter().all(|word| trie.is_valid_word(word)) {
+                            moves.insert(board_move);
+                        }
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    #[test]
+    fn generates_the_same_moves_as_brute_force() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let board = board_with_cat();
+
+        for rack in [
+            vec![Tile::A, Tile::S, Tile::T],
+            vec![Tile::C, Tile::A, Tile::T, Tile::S],
+            vec![Tile::Blank, Tile::A, Tile::T],
+        ] {
+            let moves = generate_moves(&board, &rack, &trie).unwrap();
+            for generated in &moves {
+                assert_eq!(
+                    Ok(&generated.evaluation),
+                    board.evaluate(&generated.board_move).as_ref()
+                );
+            }
+
+            assert_eq!(
+                generated(&board, &rack, &trie),
+                brute_force(&board, &rack, &trie),
+                "{rack:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn cross_checks_allow_letters_that_form_words() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let board = board_with_cat();
+        let mut generator = MoveGenerator::new(&board, &[], &trie);
+        generator.horizontal = true;
+
+        let mask = |tiles: &[Tile]| tiles.iter().fold(0, |mask, tile| mask | 1 << *tile as u32);
+        // Below the A: AA, AS and AT.
+        assert_eq!(
+            generator.cross_check(8, 7).unwrap(),
+            mask(&[Tile::A, Tile::S, Tile::T])
+        );
+        // Above the T: AT.
+        assert_eq!(generator.cross_check(6, 8).unwrap(), mask(&[Tile::A]));
+        // Below the C nothing fits.
+        assert_eq!(generator.cross_check(8, 6).unwrap(), 0);
+        // Squares without vertical neighbours allow every tile.
+        assert_eq!(generator.cross_check(8, 10).unwrap(), ALL_TILES);
+    }
+
+    #[test]
+    fn blanks_are_used_in_left_parts_and_extensions() {
+        let trie = WordTrie::from_words(Language::English, ["AT"]);
+        let moves = generated(&empty_board(), &[Tile::Blank, Tile::T], &trie);
+
+        // The blank left of the anchor, or on the anchor with the T to its right.
+        let left_part = BoardMove::new(vec![
+            BoardMovePart::blank(coords(6, 7), Tile::A),
+            BoardMovePart::new(coords(7, 7), Tile::T),
+        ]);
+        let extension = BoardMove::new(vec![
+            BoardMovePart::blank(coords(7, 7), Tile::A),
+            BoardMovePart::new(coords(8, 7), Tile::T),
+        ]);
+        assert!(moves.contains(&left_part));
+        assert!(moves.contains(&extension));
+        assert_eq!(moves.len(), 4);
+    }
+
+    #[test]
+    fn first_moves_cover_the_anchor() {
+        let trie = WordTrie::from_words(Language::English, ["A", "AT", "CAT"]);
+        let moves = generate_moves(&empty_board(), &[Tile::C, Tile::A, Tile::T], &trie).unwrap();
+
+        // CAT and AT in both directions, on every square range that covers the anchor.
+        assert_eq!(moves.len(), 10);
+        for generated in &moves {
+            assert!(generated.board_move.get_part(&coords(7, 7)).is_some());
+            assert!(generated.board_move.parts.len() > 1);
+        }
+    }
+
+    #[test]
+    fn single_tiles_are_generated_once() {
+        let trie = WordTrie::from_words(Language::English, WORDS.iter().copied());
+        let moves = generate_moves(&board_with_cat(), &[Tile::S], &trie).unwrap();
+
+        let unique: HashSet<&BoardMove> = moves
+            .iter()
+            .map(|generated| &generated.board_move)
+            .collect();
+        assert_eq!(unique.len(), moves.len());
+        // SCAT, CATS, and AS below the A.
+        assert_eq!(moves.len(), 3);
+    }
+}
@@ -0,0 +1,59 @@
+//! Everything the game needs to know about a language, from its tiles to its dictionary sources.
+//!
+//! Adding a language means adding a definition module and listing it in `types/language.rs`.
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::types::language::Language;
+
+mod dutch;
+mod english;
+mod french;
+mod german;
+mod spanish;
+
+pub(crate) use dutch::DUTCH;
+pub(crate) use english::ENGLISH;
+pub(crate) use french::FRENCH;
+pub(crate) use german::GERMAN;
+pub(crate) use spanish::SPANISH;
+
+#[derive(Debug)]
+pub struct LanguageDefinition {
+    pub language: Language,
+    /// The English name of the language.
+    pub name: &'static str,
+    /// The name of the language in the language itself, shown to the players.
+    pub native_name: &'static str,
+    /// The letter tiles and their values.
+    pub alphabet: Alphabet,
+    /// How many of every tile the bag holds, including the blanks.
+    pub distribution: &'static [(Tile, u8)],
+    /// The value of keeping a single tile on the rack, in points.
+    pub leave: &'static [(Tile, f32)],
+    pub vowels: &'static [Tile],
+    /// Lowercase letters of the written language that are played with other tiles.
+    pub folded_letters: &'static [(char, &'static str)],
+    /// The dictionary is read from `kaikki.org-dictionary-<kaikki_name>.jsonl`.
+    pub kaikki_name: &'static str,
+    /// The list of playable words, one per line.
+    pub whitelist_file: &'static str,
+}
+
+impl LanguageDefinition {
+    /// Lowercases the word and replaces the letters that have no tile of their own,
+    /// the result is how the word is spelled on the board.
+    pub fn normalize_word(&self, word: &str) -> String {
+        let mut normalized = String::with_capacity(word.len());
+        for char in word.chars().flat_map(char::to_lowercase) {
+            match self
+                .folded_letters
+                .iter()
+                .find(|(letter, _)| *letter == char)
+            {
+                Some((_, replacement)) => normalized.push_str(replacement),
+                None => normalized.push(char),
+            }
+        }
+        normalized
+    }
+}
@@ -0,0 +1,124 @@
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::game::language::LanguageDefinition;
+use crate::types::language::Language;
+
+pub static DUTCH: LanguageDefinition = LanguageDefinition {
+    language: Language::Dutch,
+    name: "Dutch",
+    native_name: "Nederlands",
+    alphabet: Alphabet::new(&[
+        (Tile::A, 1),
+        (Tile::B, 3),
+        (Tile::C, 5),
+        (Tile::D, 2),
+        (Tile::E, 1),
+        (Tile::F, 4),
+        (Tile::G, 3),
+        (Tile::H, 4),
+        (Tile::I, 1),
+        (Tile::J, 4),
+        (Tile::K, 3),
+        (Tile::L, 3),
+        (Tile::M, 3),
+        (Tile::N, 1),
+        (Tile::O, 1),
+        (Tile::P, 3),
+        (Tile::Q, 10),
+        (Tile::R, 2),
+        (Tile::S, 2),
+        (Tile::T, 2),
+        (Tile::U, 4),
+        (Tile::V, 4),
+        (Tile::W, 5),
+        (Tile::X, 8),
+        (Tile::Y, 8),
+        (Tile::Z, 4),
+    ]),
+    distribution: &[
+        (Tile::A, 6),
+        (Tile::B, 2),
+        (Tile::C, 2),
+        (Tile::D, 5),
+        (Tile::E, 18),
+        (Tile::F, 2),
+        (Tile::G, 3),
+        (Tile::H, 2),
+        (Tile::I, 4),
+        (Tile::J, 2),
+        (Tile::K, 3),
+        (Tile::L, 3),
+        (Tile::M, 3),
+        (Tile::N, 10),
+        (Tile::O, 6),
+        (Tile::P, 2),
+        (Tile::Q, 1),
+        (Tile::R, 5),
+        (Tile::S, 5),
+        (Tile::T, 5),
+        (Tile::U, 3),
+        (Tile::V, 2),
+        (Tile::W, 2),
+        (Tile::X, 1),
+        (Tile::Y, 1),
+        (Tile::Z, 2),
+        (Tile::Blank, 2),
+    ],
+    // Hand-tuned estimates.
+    leave: &[
+        (Tile::A, 0.0),
+        (Tile::B, -1.5),
+        (Tile::C, -2.5),
+        (Tile::D, 0.5),
+        (Tile::E, 2.5),
+        (Tile::F, -1.5),
+        (Tile::G, -0.5),
+        (Tile::H, -1.0),
+        (Tile::I, 0.0),
+        (Tile::J, -2.0),
+        (Tile::K, -0.5),
+        (Tile::L, 0.5),
+        (Tile::M, -0.5),
+        (Tile::N, 2.0),
+        (Tile::O, -1.0),
+        (Tile::P, -1.5),
+        (Tile::Q, -6.0),
+        (Tile::R, 1.0),
+        (Tile::S, 1.5),
+        (Tile::T, 1.0),
+        (Tile::U, -2.0),
+        (Tile::V, -2.0),
+        (Tile::W, -2.0),
+        (Tile::X, -3.0),
+        (Tile::Y, -4.0),
+        (Tile::Z, -1.5),
+        (Tile::Blank, 20.0),
+    ],
+    vowels: &[Tile::A, Tile::E, Tile::I, Tile::O, Tile::U],
+    // Like the official set there is no IJ tile, it is spelled with I and J.
+    folded_letters: &[
+        ('á', "a"),
+        ('à', "a"),
+        ('â', "a"),
+        ('ä', "a"),
+        ('é', "e"),
+        ('è', "e"),
+        ('ê', "e"),
+        ('ë', "e"),
+        ('í', "i"),
+        ('ì', "i"),
+        ('î', "i"),
+        ('ï', "i"),
+        ('ĳ', "ij"),
+        ('ó', "o"),
+        ('ò', "o"),
+        ('ô', "o"),
+        ('ö', "o"),
+        ('ú', "u"),
+        ('ù', "u"),
+        ('û', "u"),
+        ('ü', "u"),
+    ],
+    kaikki_name: "Nederlands",
+    whitelist_file: "dutch_whitelist.txt",
+};
@@ -0,0 +1,101 @@
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::game::language::LanguageDefinition;
+use crate::types::language::Language;
+
+pub static ENGLISH: LanguageDefinition = LanguageDefinition {
+    language: Language::English,
+    name: "English",
+    native_name: "English",
+    alphabet: Alphabet::new(&[
+        (Tile::A, 1),
+        (Tile::B, 3),
+        (Tile::C, 3),
+        (Tile::D, 2),
+        (Tile::E, 1),
+        (Tile::F, 4),
+        (Tile::G, 2),
+        (Tile::H, 4),
+        (Tile::I, 1),
+        (Tile::J, 8),
+        (Tile::K, 5),
+        (Tile::L, 1),
+        (Tile::M, 3),
+        (Tile::N, 1),
+        (Tile::O, 1),
+        (Tile::P, 3),
+        (Tile::Q, 10),
+        (Tile::R, 1),
+        (Tile::S, 1),
+        (Tile::T, 1),
+        (Tile::U, 1),
+        (Tile::V, 4),
+        (Tile::W, 4),
+        (Tile::X, 8),
+        (Tile::Y, 4),
+        (Tile::Z, 10),
+    ]),
+    distribution: &[
+        (Tile::A, 9),
+        (Tile::B, 2),
+        (Tile::C, 2),
+        (Tile::D, 4),
+        (Tile::E, 12),
+        (Tile::F, 2),
+        (Tile::G, 3),
+        (Tile::H, 2),
+        (Tile::I, 9),
+        (Tile::J, 1),
+        (Tile::K, 1),
+        (Tile::L, 4),
+        (Tile::M, 2),
+        (Tile::N, 6),
+        (Tile::O, 8),
+        (Tile::P, 2),
+        (Tile::Q, 1),
+        (Tile::R, 6),
+        (Tile::S, 4),
+        (Tile::T, 6),
+        (Tile::U, 4),
+        (Tile::V, 2),
+        (Tile::W, 2),
+        (Tile::X, 1),
+        (Tile::Y, 2),
+        (Tile::Z, 1),
+        (Tile::Blank, 2),
+    ],
+    // Rounded from published single tile leave tables.
+    leave: &[
+        (Tile::A, 0.6),
+        (Tile::B, -2.0),
+        (Tile::C, 0.9),
+        (Tile::D, 0.4),
+        (Tile::E, 0.7),
+        (Tile::F, -2.2),
+        (Tile::G, -2.9),
+        (Tile::H, 1.1),
+        (Tile::I, -0.9),
+        (Tile::J, -1.5),
+        (Tile::K, -0.5),
+        (Tile::L, -0.2),
+        (Tile::M, 0.6),
+        (Tile::N, 0.2),
+        (Tile::O, -2.3),
+        (Tile::P, -0.6),
+        (Tile::Q, -7.2),
+        (Tile::R, 1.1),
+        (Tile::S, 7.8),
+        (Tile::T, -0.1),
+        (Tile::U, -5.1),
+        (Tile::V, -5.5),
+        (Tile::W, -3.8),
+        (Tile::X, 3.3),
+        (Tile::Y, -0.6),
+        (Tile::Z, 5.1),
+        (Tile::Blank, 24.0),
+    ],
+    vowels: &[Tile::A, Tile::E, Tile::I, Tile::O, Tile::U],
+    folded_letters: &[],
+    kaikki_name: "English",
+    whitelist_file: "english_whitelist.txt",
+};
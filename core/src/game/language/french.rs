@@ -0,0 +1,121 @@
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::game::language::LanguageDefinition;
+use crate::types::language::Language;
+
+pub static FRENCH: LanguageDefinition = LanguageDefinition {
+    language: Language::French,
+    name: "French",
+    native_name: "Français",
+    alphabet: Alphabet::new(&[
+        (Tile::A, 1),
+        (Tile::B, 3),
+        (Tile::C, 3),
+        (Tile::D, 2),
+        (Tile::E, 1),
+        (Tile::F, 4),
+        (Tile::G, 2),
+        (Tile::H, 4),
+        (Tile::I, 1),
+        (Tile::J, 8),
+        (Tile::K, 10),
+        (Tile::L, 1),
+        (Tile::M, 2),
+        (Tile::N, 1),
+        (Tile::O, 1),
+        (Tile::P, 3),
+        (Tile::Q, 8),
+        (Tile::R, 1),
+        (Tile::S, 1),
+        (Tile::T, 1),
+        (Tile::U, 1),
+        (Tile::V, 4),
+        (Tile::W, 10),
+        (Tile::X, 10),
+        (Tile::Y, 10),
+        (Tile::Z, 10),
+    ]),
+    distribution: &[
+        (Tile::A, 9),
+        (Tile::B, 2),
+        (Tile::C, 2),
+        (Tile::D, 3),
+        (Tile::E, 15),
+        (Tile::F, 2),
+        (Tile::G, 2),
+        (Tile::H, 2),
+        (Tile::I, 8),
+        (Tile::J, 1),
+        (Tile::K, 1),
+        (Tile::L, 5),
+        (Tile::M, 3),
+        (Tile::N, 6),
+        (Tile::O, 6),
+        (Tile::P, 2),
+        (Tile::Q, 1),
+        (Tile::R, 6),
+        (Tile::S, 6),
+        (Tile::T, 6),
+        (Tile::U, 6),
+        (Tile::V, 2),
+        (Tile::W, 1),
+        (Tile::X, 1),
+        (Tile::Y, 1),
+        (Tile::Z, 1),
+        (Tile::Blank, 2),
+    ],
+    // Hand-tuned estimates.
+    leave: &[
+        (Tile::A, 0.5),
+        (Tile::B, -1.5),
+        (Tile::C, -0.5),
+        (Tile::D, -0.5),
+        (Tile::E, 2.0),
+        (Tile::F, -1.5),
+        (Tile::G, -1.5),
+        (Tile::H, -1.0),
+        (Tile::I, 0.5),
+        (Tile::J, -3.0),
+        (Tile::K, -5.0),
+        (Tile::L, 0.5),
+        (Tile::M, -0.5),
+        (Tile::N, 0.5),
+        (Tile::O, -1.0),
+        (Tile::P, -1.0),
+        (Tile::Q, -3.5),
+        (Tile::R, 1.5),
+        (Tile::S, 5.0),
+        (Tile::T, 1.0),
+        (Tile::U, -1.0),
+        (Tile::V, -3.0),
+        (Tile::W, -6.0),
+        (Tile::X, 1.0),
+        (Tile::Y, -2.5),
+        (Tile::Z, 0.5),
+        (Tile::Blank, 22.0),
+    ],
+    vowels: &[Tile::A, Tile::E, Tile::I, Tile::O, Tile::U],
+    // The tiles have no accents, so accented letters are played as their base letter.
+    folded_letters: &[
+        ('à', "a"),
+        ('â', "a"),
+        ('ä', "a"),
+        ('æ', "ae"),
+        ('ç', "c"),
+        ('è', "e"),
+        ('é', "e"),
+        ('ê', "e"),
+        ('ë', "e"),
+        ('î', "i"),
+        ('ï', "i"),
+        ('ô', "o"),
+        ('ö', "o"),
+        ('œ', "oe"),
+        ('ù', "u"),
+        ('û', "u"),
+        ('ü', "u"),
+        ('ÿ', "y"),
+    ],
+    kaikki_name: "Français",
+    whitelist_file: "french_whitelist.txt",
+};
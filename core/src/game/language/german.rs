@@ -0,0 +1,120 @@
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::game::language::LanguageDefinition;
+use crate::types::language::Language;
+
+pub static GERMAN: LanguageDefinition = LanguageDefinition {
+    language: Language::German,
+    name: "German",
+    native_name: "Deutsch",
+    alphabet: Alphabet::new(&[
+        (Tile::A, 1),
+        (Tile::B, 3),
+        (Tile::C, 4),
+        (Tile::D, 1),
+        (Tile::E, 1),
+        (Tile::F, 4),
+        (Tile::G, 2),
+        (Tile::H, 2),
+        (Tile::I, 1),
+        (Tile::J, 6),
+        (Tile::K, 4),
+        (Tile::L, 2),
+        (Tile::M, 3),
+        (Tile::N, 1),
+        (Tile::O, 2),
+        (Tile::P, 4),
+        (Tile::Q, 10),
+        (Tile::R, 1),
+        (Tile::S, 1),
+        (Tile::T, 1),
+        (Tile::U, 1),
+        (Tile::V, 6),
+        (Tile::W, 3),
+        (Tile::X, 8),
+        (Tile::Y, 10),
+        (Tile::Z, 3),
+        (Tile::AE, 6),
+        (Tile::OE, 8),
+        (Tile::UE, 6),
+    ]),
+    distribution: &[
+        (Tile::A, 5),
+        (Tile::B, 2),
+        (Tile::C, 2),
+        (Tile::D, 4),
+        (Tile::E, 15),
+        (Tile::F, 2),
+        (Tile::G, 3),
+        (Tile::H, 4),
+        (Tile::I, 6),
+        (Tile::J, 1),
+        (Tile::K, 2),
+        (Tile::L, 3),
+        (Tile::M, 4),
+        (Tile::N, 9),
+        (Tile::O, 3),
+        (Tile::P, 1),
+        (Tile::Q, 1),
+        (Tile::R, 6),
+        (Tile::S, 7),
+        (Tile::T, 6),
+        (Tile::U, 6),
+        (Tile::V, 1),
+        (Tile::W, 1),
+        (Tile::X, 1),
+        (Tile::Y, 1),
+        (Tile::Z, 1),
+        (Tile::AE, 1),
+        (Tile::OE, 1),
+        (Tile::UE, 1),
+        (Tile::Blank, 2),
+    ],
+    // Hand-tuned estimates.
+    leave: &[
+        (Tile::A, 0.5),
+        (Tile::B, -1.5),
+        (Tile::C, -1.0),
+        (Tile::D, 0.5),
+        (Tile::E, 2.0),
+        (Tile::F, -1.5),
+        (Tile::G, -0.5),
+        (Tile::H, 0.5),
+        (Tile::I, 0.5),
+        (Tile::J, -3.0),
+        (Tile::K, -1.5),
+        (Tile::L, 0.5),
+        (Tile::M, -0.5),
+        (Tile::N, 2.0),
+        (Tile::O, -1.5),
+        (Tile::P, -2.0),
+        (Tile::Q, -6.0),
+        (Tile::R, 1.5),
+        (Tile::S, 2.5),
+        (Tile::T, 1.0),
+        (Tile::U, -0.5),
+        (Tile::V, -4.0),
+        (Tile::W, -2.0),
+        (Tile::X, -5.0),
+        (Tile::Y, -6.0),
+        (Tile::Z, -1.5),
+        (Tile::AE, -3.0),
+        (Tile::OE, -3.5),
+        (Tile::UE, -3.0),
+        (Tile::Blank, 20.0),
+    ],
+    vowels: &[
+        Tile::A,
+        Tile::E,
+        Tile::I,
+        Tile::O,
+        Tile::U,
+        Tile::AE,
+        Tile::OE,
+        Tile::UE,
+    ],
+    // There is no ß tile, it is written as SS.
+    folded_letters: &[('ß', "ss")],
+    kaikki_name: "Deutsch",
+    whitelist_file: "german_whitelist.txt",
+};
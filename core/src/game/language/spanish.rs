@@ -0,0 +1,115 @@
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::game::language::LanguageDefinition;
+use crate::types::language::Language;
+
+pub static SPANISH: LanguageDefinition = LanguageDefinition {
+    language: Language::Spanish,
+    name: "Spanish",
+    native_name: "Español",
+    alphabet: Alphabet::new(&[
+        (Tile::A, 1),
+        (Tile::B, 3),
+        (Tile::C, 3),
+        (Tile::CH, 5),
+        (Tile::D, 2),
+        (Tile::E, 1),
+        (Tile::F, 4),
+        (Tile::G, 2),
+        (Tile::H, 4),
+        (Tile::I, 1),
+        (Tile::J, 8),
+        (Tile::L, 1),
+        (Tile::LL, 8),
+        (Tile::M, 3),
+        (Tile::N, 1),
+        (Tile::NY, 8),
+        (Tile::O, 1),
+        (Tile::P, 3),
+        (Tile::Q, 5),
+        (Tile::R, 1),
+        (Tile::RR, 8),
+        (Tile::S, 1),
+        (Tile::T, 1),
+        (Tile::U, 1),
+        (Tile::V, 4),
+        (Tile::X, 8),
+        (Tile::Y, 4),
+        (Tile::Z, 10),
+    ]),
+    distribution: &[
+        (Tile::A, 12),
+        (Tile::B, 2),
+        (Tile::C, 4),
+        (Tile::CH, 1),
+        (Tile::D, 5),
+        (Tile::E, 12),
+        (Tile::F, 1),
+        (Tile::G, 2),
+        (Tile::H, 2),
+        (Tile::I, 6),
+        (Tile::J, 1),
+        (Tile::L, 4),
+        (Tile::LL, 1),
+        (Tile::M, 2),
+        (Tile::N, 5),
+        (Tile::NY, 1),
+        (Tile::O, 9),
+        (Tile::P, 2),
+        (Tile::Q, 1),
+        (Tile::R, 5),
+        (Tile::RR, 1),
+        (Tile::S, 6),
+        (Tile::T, 4),
+        (Tile::U, 5),
+        (Tile::V, 1),
+        (Tile::X, 1),
+        (Tile::Y, 1),
+        (Tile::Z, 1),
+        (Tile::Blank, 2),
+    ],
+    // Hand-tuned estimates.
+    leave: &[
+        (Tile::A, 1.0),
+        (Tile::B, -1.5),
+        (Tile::C, 0.0),
+        (Tile::CH, -3.5),
+        (Tile::D, 0.5),
+        (Tile::E, 1.0),
+        (Tile::F, -2.0),
+        (Tile::G, -1.5),
+        (Tile::H, -1.5),
+        (Tile::I, 0.0),
+        (Tile::J, -2.0),
+        (Tile::L, 0.5),
+        (Tile::LL, -4.0),
+        (Tile::M, -0.5),
+        (Tile::N, 1.0),
+        (Tile::NY, -3.0),
+        (Tile::O, 0.0),
+        (Tile::P, -1.0),
+        (Tile::Q, -5.0),
+        (Tile::R, 1.5),
+        (Tile::RR, -3.5),
+        (Tile::S, 4.0),
+        (Tile::T, 0.5),
+        (Tile::U, -1.5),
+        (Tile::V, -3.0),
+        (Tile::X, -2.0),
+        (Tile::Y, -2.0),
+        (Tile::Z, 1.0),
+        (Tile::Blank, 22.0),
+    ],
+    vowels: &[Tile::A, Tile::E, Tile::I, Tile::O, Tile::U],
+    // Accents are dropped, the Ñ has a tile of its own.
+    folded_letters: &[
+        ('á', "a"),
+        ('é', "e"),
+        ('í', "i"),
+        ('ó', "o"),
+        ('ú', "u"),
+        ('ü', "u"),
+    ],
+    kaikki_name: "Español",
+    whitelist_file: "spanish_whitelist.txt",
+};
@@ -0,0 +1,239 @@
+use crate::game::board::board_move::{BoardMove, BoardMoveEvaluation};
+use crate::game::board::tile::Tile;
+use crate::game::generator::GeneratedMove;
+use crate::game::rules::GameRules;
+use crate::types::language::Language;
+
+/// Penalty for every additional copy of the same tile.
+const DUPLICATE_PENALTY: f32 = 2.0;
+/// Penalty for every vowel or consonant beyond a difference of one.
+const IMBALANCE_PENALTY: f32 = 1.5;
+
+pub fn leave_table(language: Language) -> &'static [(Tile, f32)] {
+    language.definition().leave
+}
+
+/// Whether the tile is a vowel, blanks count as neither vowel nor consonant.
+pub fn is_vowel(tile: Tile, language: Language) -> bool {
+    language.definition().vowels.contains(&tile)
+}
+
+/// How much the tiles kept on the rack are worth for the next turns, in points.
+///
+/// Sums the single tile values and penalizes duplicates and an imbalance of vowels and consonants.
+pub fn leave_value(leave: &[Tile], language: Language) -> f32 {
+    let table = leave_table(language);
+    let mut value = 0.0;
+    let mut vowels = 0i32;
+    let mut consonants = 0i32;
+
+    for (index, tile) in leave.iter().enumerate() {
+        value += table
+            .iter()
+            .find(|(table_tile, _)| table_tile == tile)
+            .map(|(_, value)| *value)
+            .unwrap_or(0.0);
+
+        if *tile != Tile::Blank && leave[..index].contains(tile) {
+            value -= DUPLICATE_PENALTY;
+        }
+
+        if is_vowel(*tile, language) {
+            vowels += 1;
+        } else if tile.is_letter() {
+            consonants += 1;
+        }
+    }
+
+    let imbalance = ((vowels - consonants).abs() - 1).max(0);
+    value - imbalance as f32 * IMBALANCE_PENALTY
+}
+
+/// The tiles that stay on the rack after the move.
+pub fn rack_leave(rack: &[Tile], board_move: &BoardMove) -> Vec<Tile> {
+    let mut leave = rack.to_vec();
+    for part in &board_move.parts {
+        if let Some(index) = leave.iter().position(|tile| *tile == part.rack_tile()) {
+            leave.remove(index);
+        }
+    }
+    leave
+}
+
+/// The score of the move plus the value of the tiles it keeps on the rack.
+pub fn equity(evaluation: &BoardMoveEvaluation, leave: &[Tile], language: Language) -> f32 {
+    evaluation.score as f32 + leave_value(leave, language)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedMove {
+    pub generated: GeneratedMove,
+    /// The score including the bingo bonus.
+    pub score: u32,
+    pub equity: f32,
+}
+
+/// Ranks the moves by equity, highest first.
+///
+/// Once the bag is empty the leave is worthless and only the score counts.
+pub fn rank_by_equity(
+    moves: Vec<GeneratedMove>,
+    rack: &[Tile],
+    rules: &GameRules,
+    tiles_in_bag: usize,
+) -> Vec<RankedMove> {
+    let mut ranked: Vec<RankedMove> = moves
+        .into_iter()
+        .map(|generated| {
+            let bingo_bonus = if generated.board_move.parts.len() == rules.rack_size as usize {
+                rules.bingo_bonus as u32
+            } else {
+                0
+            };
+
+            let leave = if tiles_in_bag == 0 {
+                Vec::new()
+            } else {
+                rack_leave(rack, &generated.board_move)
+            };
+
+            RankedMove {
+                score: generated.evaluation.score + bingo_bonus,
+                equity: equity(&generated.evaluation, &leave, rules.language) + bingo_bonus as f32,
+                generated,
+            }
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.equity.total_cmp(&a.equity));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::board_move::BoardMovePart;
+    use crate::game::board::coordinates::BoardCoords;
+
+    const ENGLISH: Language = Language::English;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn single(tile: Tile) -> f32 {
+        leave_value(&[tile], ENGLISH)
+    }
+
+    /// A move that plays the tiles from left to right on the center row.
+    fn generated(tiles: &[Tile], score: u32) -> GeneratedMove {
+        let parts = tiles
+            .iter()
+            .enumerate()
+            .map(|(x, tile)| {
+                BoardMovePart::new(BoardCoords::from_x_y(x as u8, 7, 15).unwrap(), *tile)
+            })
+            .collect();
+
+        GeneratedMove {
+            board_move: BoardMove::new(parts),
+            evaluation: BoardMoveEvaluation {
+                score,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn single_tiles_are_worth_their_table_value() {
+        assert_close(single(Tile::S), 7.8);
+        assert_close(single(Tile::Q), -7.2);
+        assert_close(leave_value(&[], ENGLISH), 0.0);
+    }
+
+    #[test]
+    fn duplicates_are_penalized() {
+        let leave = [Tile::A, Tile::A, Tile::S, Tile::S];
+        let singles = 2.0 * single(Tile::A) + 2.0 * single(Tile::S);
+        assert_close(
+            leave_value(&leave, ENGLISH),
+            singles - 2.0 * DUPLICATE_PENALTY,
+        );
+    }
+
+    #[test]
+    fn vowel_and_consonant_imbalance_is_penalized() {
+        let vowels = [Tile::A, Tile::E, Tile::I, Tile::O];
+        let singles: f32 = vowels.iter().map(|tile| single(*tile)).sum();
+        assert_close(
+            leave_value(&vowels, ENGLISH),
+            singles - 3.0 * IMBALANCE_PENALTY,
+        );
+
+        let balanced = [Tile::A, Tile::R, Tile::S];
+        let singles: f32 = balanced.iter().map(|tile| single(*tile)).sum();
+        assert_close(leave_value(&balanced, ENGLISH), singles);
+    }
+
+    #[test]
+    fn blanks_are_neither_duplicates_nor_unbalanced() {
+        assert_close(single(Tile::Blank), 24.0);
+        assert_close(leave_value(&[Tile::Blank, Tile::Blank], ENGLISH), 48.0);
+    }
+
+    #[test]
+    fn the_leave_is_what_the_move_does_not_play() {
+        let rack = [Tile::Q, Tile::S, Tile::Blank, Tile::S];
+        let board_move = BoardMove::new(vec![
+            BoardMovePart::new(BoardCoords::from_x_y(7, 7, 15).unwrap(), Tile::S),
+            BoardMovePart::blank(BoardCoords::from_x_y(8, 7, 15).unwrap(), Tile::E),
+        ]);
+        assert_eq!(rack_leave(&rack, &board_move), vec![Tile::Q, Tile::S]);
+    }
+
+    #[test]
+    fn good_leaves_outrank_higher_scores() {
+        let rules = GameRules::classic_english();
+        let moves = vec![generated(&[Tile::S], 12), generated(&[Tile::Q], 8)];
+        let ranked = rank_by_equity(moves, &[Tile::Q, Tile::S], &rules, 50);
+
+        assert_eq!(ranked[0].generated.board_move.parts[0].tile, Tile::Q);
+        assert_close(ranked[0].equity, 8.0 + single(Tile::S));
+        assert_close(ranked[1].equity, 12.0 + single(Tile::Q));
+    }
+
+    #[test]
+    fn bingos_include_the_bonus() {
+        let rules = GameRules::classic_english();
+        let rack = [
+            Tile::A,
+            Tile::E,
+            Tile::I,
+            Tile::R,
+            Tile::S,
+            Tile::T,
+            Tile::Q,
+        ];
+        let moves = vec![generated(&[Tile::S], 30), generated(&rack, 10)];
+        let ranked = rank_by_equity(moves, &rack, &rules, 50);
+
+        assert_eq!(ranked[0].generated.board_move.parts.len(), 7);
+        assert_eq!(ranked[0].score, 60);
+        assert_close(ranked[0].equity, 60.0);
+        assert_eq!(ranked[1].score, 30);
+    }
+
+    #[test]
+    fn only_the_score_counts_once_the_bag_is_empty() {
+        let rules = GameRules::classic_english();
+        let moves = vec![generated(&[Tile::Q], 8), generated(&[Tile::S], 12)];
+        let ranked = rank_by_equity(moves, &[Tile::Q, Tile::S], &rules, 0);
+
+        assert_eq!(ranked[0].generated.board_move.parts[0].tile, Tile::S);
+        assert_close(ranked[0].equity, 12.0);
+        assert_close(ranked[1].equity, 8.0);
+    }
+}
@@ -0,0 +1,264 @@
+//! A compact text format for a position, similar to FEN in chess:
+//!
+//! `<board> <racks> <bag> <player to move>`
+//!
+//! - The board rows are separated by `/`, starting at the top. Numbers are runs of empty squares,
+//!   uppercase letters are tiles and lowercase letters are blanks.
+//! - Multi-letter tiles are enclosed in brackets, like `[CH]` or `[ch]` for a blank.
+//! - The racks are separated by `/`, blanks are `?`.
+//! - The bag is written in draw order, the last tile is drawn first.
+//! - Empty racks and an empty bag are written as `-`.
+//! - The player to move is the index of the player, starting at 0.
+//!
+//! An empty 15x15 board with two racks: `15/15/15/15/15/15/15/15/15/15/15/15/15/15/15 ?ABCDEF/GHIJKLM NOP 0`
+use crate::game::bag::tile_distribution;
+use crate::game::board::board_move::{tile_notation, BoardMove, BoardMovePart};
+use crate::game::board::coordinates::BoardCoords;
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::{split_notation, Tile};
+use crate::game::board::Board;
+use crate::game::error::{GameError, GameResult};
+use crate::game::rules::GameRules;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct Position {
+    pub board: Board,
+    pub racks: Vec<Vec<Tile>>,
+    pub bag: Vec<Tile>,
+    pub current_player: u8,
+}
+
+impl Position {
+    /// The board size, language and bonus layout are taken from the rules.
+    ///
+    /// Every tile has to be part of the language's alphabet, and no tile may appear
+    /// more often than the language's tile set holds it.
+    pub fn parse(text: &str, rules: &GameRules) -> GameResult<Self> {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        let [board, racks, bag, current_player] = fields[..] else {
+            return Err(GameError::InvalidPosition(format!(
+                "Expected 4 fields, got {}",
+                fields.len()
+            )));
+        };
+
+        let alphabet = Alphabet::for_language(rules.language);
+        let board = parse_board(board, rules)?;
+        let racks = racks
+            .split('/')
+            .map(|rack| parse_tiles(rack, alphabet))
+            .collect::<GameResult<Vec<_>>>()?;
+        let bag = parse_tiles(bag, alphabet)?;
+        let current_player: u8 = current_player.parse().map_err(|_| {
+            GameError::InvalidPosition(format!("Invalid player to move: {current_player}"))
+        })?;
+
+        if current_player as usize >= racks.len() {
+            return Err(GameError::InvalidPosition(format!(
+                "Player to move {current_player} has no rack"
+            )));
+        }
+
+        check_tile_counts(&board, &racks, &bag, rules)?;
+
+        Ok(Self {
+            board,
+            racks,
+            bag,
+            current_player,
+        })
+    }
+
+    pub fn format(&self) -> GameResult<String> {
+        let racks = self
+            .racks
+            .iter()
+            .map(|rack| format_tiles(rack))
+            .collect::<GameResult<Vec<_>>>()?;
+
+        Ok(format!(
+            "{} {} {} {}",
+            format_board(&self.board)?,
+            racks.join("/"),
+            format_tiles(&self.bag)?,
+            self.current_player
+        ))
+    }
+}
+
+fn parse_board(text: &str, rules: &GameRules) -> GameResult<Board> {
+    let mut board = Board::new(rules)?;
+    let size = board.size();
+
+    let rows: Vec<&str> = text.split('/').collect();
+    if rows.len() != size as usize {
+        return Err(GameError::InvalidPosition(format!(
+            "Expected {size} rows, got {}",
+            rows.len()
+        )));
+    }
+
+    let alphabet = Alphabet::for_language(rules.language);
+    let mut parts = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        let mut x = 0usize;
+        let mut empty = 0usize;
+        for symbol in split_notation(row)? {
+            if let Ok(digit) = symbol.parse::<usize>() {
+                empty = empty * 10 + digit;
+                continue;
+            }
+
+            x += std::mem::take(&mut empty);
+            let coords = BoardCoords::from_x_y(x as u8, y as u8, size)
+                .map_err(|_| GameError::InvalidPosition(format!("Row {} is too long", y + 1)))?;
+            let part = BoardMovePart::from_notation(coords, symbol)?;
+            if part.tile == Tile::Blank {
+                return Err(GameError::BlankNotAssigned);
+            }
+            if !alphabet.contains(part.tile) {
+                return Err(GameError::TileNotInAlphabet(part.tile));
+            }
+            parts.push(part);
+            x += 1;
+        }
+        x += empty;
+
+        if x != size as usize {
+            return Err(GameError::InvalidPosition(format!(
+                "Row {} has {x} squares instead of {size}",
+                y + 1
+            )));
+        }
+    }
+
+    board.place_tiles(&BoardMove::new(parts))?;
+    Ok(board)
+}
+
+fn check_tile_counts(
+    board: &Board,
+    racks: &[Vec<Tile>],
+    bag: &[Tile],
+    rules: &GameRules,
+) -> GameResult<()> {
+    let size = board.size();
+    let mut tiles: Vec<Tile> = racks.iter().flatten().chain(bag).copied().collect();
+    for y in 0..size {
+        for x in 0..size {
+            let cell = board.get_cell(&BoardCoords::from_x_y(x, y, size)?)?;
+            if cell.blank {
+                tiles.push(Tile::Blank);
+            } else if cell.tile != Tile::Empty {
+                tiles.push(cell.tile);
+            }
+        }
+    }
+
+    for (tile, available) in tile_distribution(rules.language) {
+        let count = tiles.iter().filter(|counted| *counted == tile).count();
+        if count > *available as usize {
+            return Err(GameError::InvalidPosition(format!(
+                "{count} {tile:?} tiles, the tile set only holds {available}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn format_board(board: &Board) -> GameResult<String> {
+    let size = board.size();
+    let mut rows = Vec::with_capacity(size as usize);
+
+    for y in 0..size {
+        let mut row = String::new();
+        let mut empty = 0;
+        for x in 0..size {
+            let cell = board.get_cell(&BoardCoords::from_x_y(x, y, size)?)?;
+            if cell.tile == Tile::Empty {
+                empty += 1;
+                continue;
+            }
+
+            if empty > 0 {
+                row.push_str(&std::mem::take(&mut empty).to_string());
+            }
+            row.push_str(&cell.notation()?);
+        }
+
+        if empty > 0 {
+            row.push_str(&empty.to_string());
+        }
+        rows.push(row);
+    }
+
+    Ok(rows.join("/"))
+}
+
+fn parse_tiles(text: &str, alphabet: &Alphabet) -> GameResult<Vec<Tile>> {
+    if text == "-" {
+        return Ok(Vec::new());
+    }
+
+    let mut tiles = Vec::new();
+    for symbol in split_notation(text)? {
+        let tile = Tile::try_from(symbol)?;
+        if !alphabet.contains(tile) {
+            return Err(GameError::TileNotInAlphabet(tile));
+        }
+        tiles.push(tile);
+    }
+    Ok(tiles)
+}
+
+fn format_tiles(tiles: &[Tile]) -> GameResult<String> {
+    if tiles.is_empty() {
+        return Ok("-".to_string());
+    }
+    tiles
+        .iter()
+        .map(|tile| tile_notation(*tile, false))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAT: &str = "15/15/15/15/15/15/15/7CAT5/15/15/15/15/15/15/15 AEINRST/AEIOU?? - 0";
+
+    #[test]
+    fn format_round_trips() {
+        let position = Position::parse(CAT, &GameRules::classic_english()).unwrap();
+        assert_eq!(position.format().unwrap(), CAT);
+    }
+
+    #[test]
+    fn tiles_beyond_the_tile_set_are_rejected() {
+        let position = Position::parse(
+            "15/15/15/15/15/15/15/7CAT5/15/15/15/15/15/15/15 ZZZQQQX/AEIOU?? - 0",
+            &GameRules::classic_english(),
+        );
+        assert!(matches!(position, Err(GameError::InvalidPosition(_))));
+    }
+
+    #[test]
+    fn blanks_on_the_board_count_as_blanks() {
+        let position = Position::parse(
+            "15/15/15/15/15/15/15/7cAT5/15/15/15/15/15/15/15 ??ABCDE/GHIJKLM - 0",
+            &GameRules::classic_english(),
+        );
+        assert!(matches!(position, Err(GameError::InvalidPosition(_))));
+    }
+
+    #[test]
+    fn blanks_on_the_board_need_a_letter() {
+        let position = Position::parse(
+            "15/15/15/15/15/15/15/7?AT5/15/15/15/15/15/15/15 AEINRST/AEIOU - 0",
+            &GameRules::classic_english(),
+        );
+        assert!(matches!(position, Err(GameError::BlankNotAssigned)));
+    }
+}
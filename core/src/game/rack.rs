@@ -0,0 +1,233 @@
+use crate::game::bag::TileBag;
+use crate::game::board::board_move::BoardMove;
+use crate::game::board::tile::Tile;
+use crate::game::error::{GameError, GameResult};
+use crate::types::language::Language;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct Rack {
+    tiles: Vec<Tile>,
+    capacity: u8,
+}
+
+impl Rack {
+    pub fn new(capacity: u8) -> Self {
+        Self {
+            tiles: Vec::with_capacity(capacity as usize),
+            capacity,
+        }
+    }
+
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    pub fn capacity(&self) -> u8 {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.tiles.len() >= self.capacity as usize
+    }
+
+    /// The sum of all tile values, used for the end of game penalty.
+    pub fn value(&self, language: Language) -> u32 {
+        self.tiles
+            .iter()
+            .map(|tile| tile.value(language) as u32)
+            .sum()
+    }
+
+    pub fn add(&mut self, tile: Tile) -> GameResult<()> {
+        if self.is_full() {
+            return Err(GameError::RackFull);
+        }
+        self.tiles.push(tile);
+        Ok(())
+    }
+
+    /// Whether the rack holds all the given tiles, counting duplicates.
+    pub fn has_tiles(&self, tiles: &[Tile]) -> bool {
+        let mut remaining = self.tiles.clone();
+        tiles.iter().all(|tile| remove_tile(&mut remaining, *tile))
+    }
+
+    pub fn validate_move(&self, board_move: &BoardMove) -> GameResult<()> {
+        if !self.has_tiles(&move_tiles(board_move)) {
+            return Err(GameError::TilesNotOnRack);
+        }
+        Ok(())
+    }
+
+    pub fn remove_tiles(&mut self, tiles: &[Tile]) -> GameResult<()> {
+        if !self.has_tiles(tiles) {
+            return Err(GameError::TilesNotOnRack);
+        }
+
+        for tile in tiles {
+            remove_tile(&mut self.tiles, *tile);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the tiles used by the move from the rack.
+    pub fn play(&mut self, board_move: &BoardMove) -> GameResult<()> {
+        self.remove_tiles(&move_tiles(board_move))
+    }
+
+    /// Draws tiles until the rack is full or the bag is empty, returns the drawn tiles.
+    pub fn refill(&mut self, bag: &mut TileBag) -> Vec<Tile> {
+        let missing = (self.capacity as usize).saturating_sub(self.tiles.len());
+        let drawn = bag.draw_many(missing);
+        self.tiles.extend_from_slice(&drawn);
+        drawn
+    }
+
+    /// Swaps the given tiles for new ones from the bag, returns the drawn tiles.
+    ///
+    /// The new tiles are drawn before the old ones are put back,
+    /// so the player can never draw the tiles they just exchanged.
+    pub fn exchange(&mut self, tiles: &[Tile], bag: &mut TileBag) -> GameResult<Vec<Tile>> {
+        if bag.remaining() < tiles.len() {
+            return Err(GameError::NotEnoughTilesInBag {
+                requested: tiles.len(),
+                remaining: bag.remaining(),
+            });
+        }
+
+        self.remove_tiles(tiles)?;
+        let drawn = bag.draw_many(tiles.len());
+        self.tiles.extend_from_slice(&drawn);
+        bag.return_tiles(tiles.iter().copied());
+
+        Ok(drawn)
+    }
+}
+
+fn move_tiles(board_move: &BoardMove) -> Vec<Tile> {
+    board_move
+        .parts
+        .iter()
+        .map(|part| part.rack_tile())
+        .collect()
+}
+
+fn remove_tile(tiles: &mut Vec<Tile>, tile: Tile) -> bool {
+    let Some(index) = tiles.iter().position(|held| *held == tile) else {
+        return false;
+    };
+    tiles.swap_remove(index);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::board_move::BoardMovePart;
+    use crate::game::board::coordinates::BoardCoords;
+
+    fn rack(tiles: &[Tile]) -> Rack {
+        let mut rack = Rack::new(7);
+        for tile in tiles {
+            rack.add(*tile).unwrap();
+        }
+        rack
+    }
+
+    #[test]
+    fn a_full_rack_takes_no_more_tiles() {
+        let mut rack = rack(&[Tile::A; 7]);
+        assert!(rack.is_full());
+        assert_eq!(rack.add(Tile::B), Err(GameError::RackFull));
+    }
+
+    #[test]
+    fn only_tiles_on_the_rack_can_be_removed() {
+        let mut rack = rack(&[Tile::A, Tile::B, Tile::A]);
+
+        assert_eq!(
+            rack.remove_tiles(&[Tile::B, Tile::B]),
+            Err(GameError::TilesNotOnRack)
+        );
+        assert_eq!(rack.len(), 3);
+
+        rack.remove_tiles(&[Tile::A, Tile::A]).unwrap();
+        assert_eq!(rack.tiles(), &[Tile::B]);
+    }
+
+    #[test]
+    fn blanks_on_the_board_take_a_blank_from_the_rack() {
+        let coords = |x| BoardCoords::from_x_y(x, 7, 15).unwrap();
+        let rack = rack(&[Tile::Blank, Tile::A]);
+
+        let blank_z = BoardMove::new(vec![
+            BoardMovePart::blank(coords(7), Tile::Z),
+            BoardMovePart::new(coords(8), Tile::A),
+        ]);
+        assert_eq!(rack.validate_move(&blank_z), Ok(()));
+
+        let two_blanks = BoardMove::new(vec![
+            BoardMovePart::blank(coords(7), Tile::Z),
+            BoardMovePart::blank(coords(8), Tile::A),
+        ]);
+        assert_eq!(
+            rack.validate_move(&two_blanks),
+            Err(GameError::TilesNotOnRack)
+        );
+
+        let regular_z = BoardMove::new(vec![BoardMovePart::new(coords(7), Tile::Z)]);
+        assert_eq!(
+            rack.validate_move(&regular_z),
+            Err(GameError::TilesNotOnRack)
+        );
+    }
+
+    #[test]
+    fn refill_stops_when_the_bag_is_empty() {
+        let mut bag = TileBag::from_tiles(vec![Tile::X, Tile::Y], 7);
+        let mut rack = rack(&[Tile::A]);
+
+        assert_eq!(rack.refill(&mut bag).len(), 2);
+        assert_eq!(rack.len(), 3);
+        assert!(bag.is_empty());
+    }
+
+    #[test]
+    fn exchanged_tiles_can_not_be_drawn_again() {
+        let mut bag = TileBag::from_tiles(vec![Tile::X, Tile::Y, Tile::Z], 7);
+        let mut rack = rack(&[Tile::A, Tile::B]);
+
+        let drawn = rack.exchange(&[Tile::A, Tile::B], &mut bag).unwrap();
+        assert_eq!(drawn.len(), 2);
+        assert!(drawn.iter().all(|tile| ![Tile::A, Tile::B].contains(tile)));
+        assert_eq!(rack.tiles(), drawn.as_slice());
+        assert_eq!(bag.remaining(), 3);
+        assert_eq!(bag.remaining_of(Tile::A), 1);
+        assert_eq!(bag.remaining_of(Tile::B), 1);
+    }
+
+    #[test]
+    fn exchanges_need_enough_tiles_in_the_bag() {
+        let mut bag = TileBag::from_tiles(vec![Tile::X], 7);
+        let mut rack = rack(&[Tile::A, Tile::B]);
+
+        assert_eq!(
+            rack.exchange(&[Tile::A, Tile::B], &mut bag),
+            Err(GameError::NotEnoughTilesInBag {
+                requested: 2,
+                remaining: 1,
+            })
+        );
+        assert_eq!(rack.len(), 2);
+    }
+}
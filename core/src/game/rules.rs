@@ -0,0 +1,134 @@
+use crate::game::board::bonus::layout::BonusLayoutKind;
+use crate::types::language::Language;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum ChallengeMode {
+    /// Moves with invalid words are rejected immediately.
+    #[default]
+    Void,
+    /// A successfully challenged move is taken back and the turn is lost.
+    Single,
+    /// Like single, but an unsuccessful challenger loses their next turn.
+    Double,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum TeamRack {
+    /// Both team members play from the same rack.
+    Shared,
+    /// Every member plays from their own rack, the game ends as soon as one member plays out.
+    #[default]
+    Separate,
+}
+
+/// Two teams of two, the partners sit opposite each other so the teams alternate turns.
+///
+/// Players 1 and 3 form the first team, players 2 and 4 the second.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct TeamRules {
+    pub rack: TeamRack,
+}
+
+impl TeamRules {
+    pub const TEAM_COUNT: u8 = 2;
+    pub const TEAM_SIZE: u8 = 2;
+}
+
+/// Clock settings, all durations are in milliseconds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct TimeControl {
+    /// Starting time of every player, 0 disables the total limit.
+    pub initial_ms: u64,
+    /// Added to the player's clock after each of their turns.
+    pub increment_ms: u64,
+    /// Points deducted at the end of the game for every started minute of overtime.
+    pub overtime_penalty: u16,
+    /// Overtime after which the player loses, 0 means the game is lost as soon as the time runs out.
+    pub max_overtime_ms: u64,
+    /// Time limit for a single turn, used for correspondence games, 0 disables it.
+    pub turn_limit_ms: u64,
+}
+
+impl TimeControl {
+    const MINUTE_MS: u64 = 60 * 1000;
+    const DAY_MS: u64 = 24 * 60 * Self::MINUTE_MS;
+
+    pub fn blitz() -> Self {
+        Self {
+            initial_ms: 5 * Self::MINUTE_MS,
+            increment_ms: 5 * 1000,
+            ..Self::default()
+        }
+    }
+
+    pub fn tournament() -> Self {
+        Self {
+            initial_ms: 25 * Self::MINUTE_MS,
+            overtime_penalty: 10,
+            max_overtime_ms: 10 * Self::MINUTE_MS,
+            ..Self::default()
+        }
+    }
+
+    pub fn correspondence(days_per_turn: u64) -> Self {
+        Self {
+            turn_limit_ms: days_per_turn * Self::DAY_MS,
+            ..Self::default()
+        }
+    }
+}
+
+/// The ruleset a game is played with, it is stored with the game.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct GameRules {
+    pub language: Language,
+    pub board_size: u8,
+    pub bonus_layout: BonusLayoutKind,
+    pub rack_size: u8,
+    /// Bonus for using every tile of a full rack in a single move.
+    pub bingo_bonus: u16,
+    /// Consecutive rounds without a score after which the game ends, 0 disables the limit.
+    ///
+    /// A round is one turn of every player still in the game.
+    pub scoreless_round_limit: u8,
+    pub challenge_mode: ChallengeMode,
+    /// Points the challenged player gains for every unsuccessful challenge of their move.
+    pub challenge_bonus: u16,
+    /// Games without a time control are untimed.
+    pub time_control: Option<TimeControl>,
+    /// Games without team rules are played every player for themselves.
+    pub teams: Option<TeamRules>,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self::classic_english()
+    }
+}
+
+impl GameRules {
+    /// The standard board and rack for the given language.
+    pub fn classic(language: Language) -> Self {
+        Self {
+            language,
+            board_size: 15,
+            bonus_layout: BonusLayoutKind::Classic,
+            rack_size: 7,
+            bingo_bonus: 50,
+            scoreless_round_limit: 3,
+            challenge_mode: ChallengeMode::Void,
+            challenge_bonus: 0,
+            time_control: None,
+            teams: None,
+        }
+    }
+
+    pub fn classic_english() -> Self {
+        Self::classic(Language::English)
+    }
+
+    pub fn classic_german() -> Self {
+        Self::classic(Language::German)
+    }
+}
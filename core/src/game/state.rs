@@ -1,9 +1,1290 @@
+use crate::game::bag::TileBag;
+use crate::game::board::board_move::{BoardMove, BoardMoveEvaluation};
+use crate::game::board::tile::Tile;
 use crate::game::board::Board;
+use crate::game::error::{GameError, GameResult};
+use crate::game::position::Position;
+use crate::game::rack::Rack;
+use crate::game::rules::{ChallengeMode, GameRules, TeamRack, TeamRules};
+use crate::game::team::{TeamMessage, TeamMessageContent};
+use crate::game::turn::{TurnAction, TurnRecord};
+use crate::game::validator::{AcceptAllWords, WordValidator};
 use bincode::{Decode, Encode};
+use std::collections::HashSet;
 
-#[derive(Debug, Encode, Decode)]
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum GameStatus {
+    Ongoing,
+    Finished(GameEndReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum GameEndReason {
+    /// The player used up all their tiles while the bag was empty.
+    ///
+    /// In team games with separate racks the partner's tiles still count against the team.
+    PlayedOut {
+        player: u8,
+    },
+    ScorelessTurns,
+    /// The player resigned a team game or left a single player behind, they can not win it.
+    ///
+    /// With more players left, a resigning player only leaves the game.
+    Resigned {
+        player: u8,
+    },
+    /// The player exceeded their time or turn limit, they can not win the game.
+    TimedOut {
+        player: u8,
+    },
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct PlayerClock {
+    /// Negative while the player is in overtime.
+    pub remaining_ms: i64,
+}
+
+/// A move that the opponent can still challenge, only used in the challenge modes.
+///
+/// The move is already on the board and scored, a successful challenge takes it back.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PendingMove {
+    pub player: u8,
+    pub board_move: BoardMove,
+    pub evaluation: BoardMoveEvaluation,
+    pub score: i32,
+    drawn: Vec<Tile>,
+    /// The scoreless turns before the move, restored if it is taken back.
+    scoreless_turns: u16,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct GameState {
-    pub player_1_id: String,
-    pub player_2_id: String,
-    pub board: Board,
+    /// The player ids in turn order.
+    players: Vec<String>,
+    rules: GameRules,
+    seed: u64,
+    board: Board,
+    bag: TileBag,
+    /// One rack per player, or per team if the team shares its rack.
+    racks: Vec<Rack>,
+    /// The points of every player, a team scores the sum of its members.
+    scores: Vec<i32>,
+    current_player: u8,
+    scoreless_turns: u16,
+    /// Players who resigned, they are skipped in the turn order.
+    resigned: Vec<bool>,
+    history: Vec<TurnRecord>,
+    /// The position the game was started from, `None` if it started from the seed.
+    start_position: Option<Position>,
+    pending: Option<PendingMove>,
+    clocks: Vec<PlayerClock>,
+    /// Stored with the game, so the team chat is kept wherever the game is.
+    team_messages: Vec<TeamMessage>,
+    /// When the current turn started, the clock starts with [`GameState::start`] or the first action.
+    turn_started_ms: Option<i64>,
+    /// When the clock was started with [`GameState::start`].
+    started_ms: Option<i64>,
+    /// When the player to move ran out of time, replays end the game at the same moment.
+    timed_out_ms: Option<i64>,
+    status: GameStatus,
+}
+
+impl GameState {
+    /// The players take turns in the given order, the first player starts.
+    pub fn new(players: Vec<String>, rules: GameRules, seed: u64) -> GameResult<Self> {
+        let team_players = (TeamRules::TEAM_COUNT * TeamRules::TEAM_SIZE) as usize;
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players.len())
+            || (rules.teams.is_some() && players.len() != team_players)
+        {
+            return Err(GameError::UnsupportedPlayerCount(players.len()));
+        }
+        for (index, player_id) in players.iter().enumerate() {
+            if players[..index].contains(player_id) {
+                return Err(GameError::DuplicatePlayer(player_id.clone()));
+            }
+        }
+
+        let board = Board::new(&rules)?;
+        let mut bag = TileBag::new(&rules, seed);
+        let mut racks = vec![Rack::new(rules.rack_size); rack_count(&rules, players.len())];
+        for rack in &mut racks {
+            rack.refill(&mut bag);
+        }
+
+        let clock = PlayerClock {
+            remaining_ms: rules
+                .time_control
+                .map_or(0, |time_control| time_control.initial_ms as i64),
+        };
+
+        Ok(Self {
+            scores: vec![0; players.len()],
+            clocks: vec![clock; players.len()],
+            resigned: vec![false; players.len()],
+            team_messages: Vec::new(),
+            players,
+            rules,
+            seed,
+            board,
+            bag,
+            racks,
+            current_player: 0,
+            scoreless_turns: 0,
+            history: Vec::new(),
+            start_position: None,
+            pending: None,
+            turn_started_ms: None,
+            started_ms: None,
+            timed_out_ms: None,
+            status: GameStatus::Ongoing,
+        })
+    }
+
+    /// Starts a game from the given position, the board has to match the rules.
+    ///
+    /// The bag keeps its order and the position is kept, replays and undos start from it
+    /// instead of the seed's racks.
+    pub fn from_position(
+        players: Vec<String>,
+        rules: GameRules,
+        position: Position,
+        seed: u64,
+    ) -> GameResult<Self> {
+        if position.board.size() != rules.board_size {
+            return Err(GameError::InvalidPosition(format!(
+                "Board size {} does not match the rules",
+                position.board.size()
+            )));
+        }
+
+        let expected_racks = rack_count(&rules, players.len());
+        if position.racks.len() != expected_racks {
+            return Err(GameError::InvalidPosition(format!(
+                "Expected {expected_racks} racks, got {}",
+                position.racks.len()
+            )));
+        }
+
+        if position.current_player as usize >= players.len() {
+            return Err(GameError::UnknownPlayer);
+        }
+
+        let start_position = position.clone();
+        let mut racks = vec![Rack::new(rules.rack_size); expected_racks];
+        for (rack, tiles) in racks.iter_mut().zip(position.racks) {
+            for tile in tiles {
+                rack.add(tile)?;
+            }
+        }
+
+        let mut state = Self::new(players, rules, seed)?;
+        state.board = position.board;
+        state.bag = TileBag::from_ordered_tiles(position.bag, seed);
+        state.racks = racks;
+        state.current_player = position.current_player;
+        state.start_position = Some(start_position);
+        Ok(state)
+    }
+
+    /// The current board, racks, bag and player to move.
+    pub fn position(&self) -> Position {
+        Position {
+            board: self.board.clone(),
+            racks: self
+                .racks
+                .iter()
+                .map(|rack| rack.tiles().to_vec())
+                .collect(),
+            bag: self.bag.tiles().to_vec(),
+            current_player: self.current_player,
+        }
+    }
+
+    pub fn rules(&self) -> &GameRules {
+        &self.rules
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn bag(&self) -> &TileBag {
+        &self.bag
+    }
+
+    pub fn players(&self) -> &[String] {
+        &self.players
+    }
+
+    pub fn player_count(&self) -> u8 {
+        self.players.len() as u8
+    }
+
+    /// The rack the player plays from, shared with their partner if the team shares its rack.
+    pub fn rack(&self, player: u8) -> Option<&Rack> {
+        if player >= self.player_count() {
+            return None;
+        }
+        self.racks.get(self.rack_index(player))
+    }
+
+    pub fn rack_count(&self) -> u8 {
+        self.racks.len() as u8
+    }
+
+    /// Shared team racks are indexed by the team, so the first players of both teams own them.
+    pub fn rack_index(&self, player: u8) -> usize {
+        match self.rules.teams {
+            Some(TeamRules {
+                rack: TeamRack::Shared,
+            }) => (player % TeamRules::TEAM_COUNT) as usize,
+            _ => player as usize,
+        }
+    }
+
+    /// The team of the player, `None` if the game is not played in teams.
+    pub fn team(&self, player: u8) -> Option<u8> {
+        self.rules
+            .teams
+            .filter(|_| player < self.player_count())
+            .map(|_| player % TeamRules::TEAM_COUNT)
+    }
+
+    pub fn team_members(&self, team: u8) -> impl Iterator<Item = u8> + '_ {
+        (0..self.player_count()).filter(move |player| self.team(*player) == Some(team))
+    }
+
+    pub fn team_score(&self, team: u8) -> Option<i32> {
+        self.rules.teams?;
+        if team >= TeamRules::TEAM_COUNT {
+            return None;
+        }
+        Some(
+            self.team_members(team)
+                .map(|player| self.scores[player as usize])
+                .sum(),
+        )
+    }
+
+    pub fn score(&self, player: u8) -> Option<i32> {
+        self.scores.get(player as usize).copied()
+    }
+
+    pub fn current_player(&self) -> u8 {
+        self.current_player
+    }
+
+    pub fn current_player_id(&self) -> &str {
+        self.player_id(self.current_player).unwrap_or_default()
+    }
+
+    pub fn player_id(&self, player: u8) -> Option<&str> {
+        self.players.get(player as usize).map(String::as_str)
+    }
+
+    pub fn player_index(&self, player_id: &str) -> GameResult<u8> {
+        self.players
+            .iter()
+            .position(|id| id == player_id)
+            .map(|index| index as u8)
+            .ok_or(GameError::UnknownPlayer)
+    }
+
+    /// The player whose turn comes after the given one, players who resigned are skipped.
+    pub fn next_player(&self, player: u8) -> u8 {
+        let mut next = (player + 1) % self.player_count();
+        while self.has_resigned(next) && next != player {
+            next = (next + 1) % self.player_count();
+        }
+        next
+    }
+
+    pub fn has_resigned(&self, player: u8) -> bool {
+        self.resigned.get(player as usize).copied().unwrap_or(false)
+    }
+
+    /// The players who have not resigned.
+    pub fn active_player_count(&self) -> u8 {
+        self.resigned.iter().filter(|resigned| !**resigned).count() as u8
+    }
+
+    pub fn history(&self) -> &[TurnRecord] {
+        &self.history
+    }
+
+    pub fn clock(&self, player: u8) -> Option<&PlayerClock> {
+        self.clocks.get(player as usize)
+    }
+
+    /// The remaining time of the player, counting the running turn.
+    pub fn remaining_ms(&self, player: u8, now_ms: i64) -> Option<i64> {
+        let clock = self.clock(player)?;
+        if player == self.current_player && !self.is_finished() {
+            Some(clock.remaining_ms - self.elapsed_ms(now_ms))
+        } else {
+            Some(clock.remaining_ms)
+        }
+    }
+
+    /// When the running turn started, `None` before the game was started.
+    pub fn turn_started_ms(&self) -> Option<i64> {
+        self.turn_started_ms
+    }
+
+    /// Starts the clock of the first player, without it the clock starts with the first action.
+    ///
+    /// Does nothing once the clock is running.
+    pub fn start(&mut self, now_ms: i64) {
+        if self.turn_started_ms.is_some() || self.is_finished() {
+            return;
+        }
+        self.turn_started_ms = Some(now_ms);
+        self.started_ms = Some(now_ms);
+    }
+
+    fn elapsed_ms(&self, now_ms: i64) -> i64 {
+        self.turn_started_ms
+            .map_or(0, |started_ms| (now_ms - started_ms).max(0))
+    }
+
+    /// Ends the game if the player to move ran out of time, returns whether the flag fell.
+    ///
+    /// The server calls this regularly, since a player who ran out of time will not act anymore.
+    pub fn check_time(&mut self, now_ms: i64) -> bool {
+        let Some(time_control) = self.rules.time_control else {
+            return false;
+        };
+        if self.is_finished() {
+            return false;
+        }
+
+        let elapsed = self.elapsed_ms(now_ms);
+        let player = self.current_player;
+        let remaining = self.clocks[player as usize].remaining_ms - elapsed;
+
+        let turn_limit_exceeded =
+            time_control.turn_limit_ms > 0 && elapsed > time_control.turn_limit_ms as i64;
+        let flag_fallen =
+            time_control.initial_ms > 0 && remaining < -(time_control.max_overtime_ms as i64);
+        if !turn_limit_exceeded && !flag_fallen {
+            return false;
+        }
+
+        self.clocks[player as usize].remaining_ms = remaining;
+        self.turn_started_ms = Some(now_ms);
+        self.timed_out_ms = Some(now_ms);
+        self.finish(GameEndReason::TimedOut { player });
+        self.charge_overtime();
+        true
+    }
+
+    /// The points the player loses for overtime at the end of the game.
+    pub fn overtime_penalty(&self, player: u8) -> i32 {
+        let (Some(time_control), Some(clock)) = (self.rules.time_control, self.clock(player))
+        else {
+            return 0;
+        };
+        if time_control.initial_ms == 0 || clock.remaining_ms >= 0 {
+            return 0;
+        }
+
+        let started_minutes = clock.remaining_ms.unsigned_abs().div_ceil(60 * 1000);
+        started_minutes as i32 * time_control.overtime_penalty as i32
+    }
+
+    /// Deducts the overtime penalties, once the clocks include the turn that ended the game.
+    fn charge_overtime(&mut self) {
+        for player in 0..self.player_count() {
+            self.scores[player as usize] -= self.overtime_penalty(player);
+        }
+    }
+
+    /// Charges the time of the turn to the player who acted, the increment is added once the turn passes.
+    fn update_clock(&mut self, player: u8, now_ms: i64) {
+        let Some(time_control) = self.rules.time_control else {
+            return;
+        };
+
+        let elapsed = self.elapsed_ms(now_ms);
+        let clock = &mut self.clocks[player as usize];
+        clock.remaining_ms -= elapsed;
+        if self.current_player != player {
+            clock.remaining_ms += time_control.increment_ms as i64;
+        }
+        self.turn_started_ms = Some(now_ms);
+    }
+
+    /// Sends a message or move suggestion to the sender's team, also possible out of turn.
+    pub fn post_team_message(
+        &mut self,
+        player_id: &str,
+        content: TeamMessageContent,
+        timestamp_ms: i64,
+    ) -> GameResult<&TeamMessage> {
+        let player = self.player_index(player_id)?;
+        let team = self.team(player).ok_or(GameError::NotATeamGame)?;
+        self.team_messages.push(TeamMessage {
+            player,
+            team,
+            content,
+            timestamp_ms,
+        });
+        Ok(&self.team_messages[self.team_messages.len() - 1])
+    }
+
+    /// The messages of the team, oldest first.
+    pub fn team_messages(&self, team: u8) -> impl Iterator<Item = &TeamMessage> + '_ {
+        self.team_messages
+            .iter()
+            .filter(move |message| message.team == team)
+    }
+
+    pub fn pending_move(&self) -> Option<&PendingMove> {
+        self.pending.as_ref()
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status, GameStatus::Finished(_))
+    }
+
+    /// The player with the highest score, `None` while the game is ongoing, on a tie or in team games.
+    ///
+    /// A player who resigned or ran out of time can not win, even with the highest score.
+    pub fn winner(&self) -> Option<u8> {
+        if !self.is_finished() || self.rules.teams.is_some() {
+            return None;
+        }
+
+        let scores = (0..self.player_count())
+            .zip(self.scores.iter().copied())
+            .filter(|(player, _)| !self.has_resigned(*player));
+        highest_score(scores, self.forfeited_by())
+    }
+
+    /// The team with the highest score, `None` while the game is ongoing, on a tie or without teams.
+    pub fn winning_team(&self) -> Option<u8> {
+        if !self.is_finished() {
+            return None;
+        }
+
+        let scores = (0..TeamRules::TEAM_COUNT)
+            .filter_map(|team| Some((team, self.team_score(team)?)))
+            .collect::<Vec<_>>();
+        let forfeited_by = self.forfeited_by().and_then(|player| self.team(player));
+        highest_score(scores.into_iter(), forfeited_by)
+    }
+
+    /// The player who resigned or ran out of time.
+    fn forfeited_by(&self) -> Option<u8> {
+        match self.status {
+            GameStatus::Finished(
+                GameEndReason::Resigned { player } | GameEndReason::TimedOut { player },
+            ) => Some(player),
+            _ => None,
+        }
+    }
+
+    /// Rebuilds the game as it was before the turn with the given index,
+    /// using the initial seed, rules and starting position.
+    ///
+    /// Every replayed turn has to draw the same tiles and score the same points
+    /// as the recorded one, otherwise the history is considered tampered with.
+    pub fn replay(&self, turn_index: usize) -> GameResult<GameState> {
+        if turn_index > self.history.len() {
+            return Err(GameError::InvalidTurnIndex(turn_index));
+        }
+
+        let players = self.players.clone();
+        let rules = self.rules.clone();
+        let mut state = match &self.start_position {
+            Some(position) => {
+                GameState::from_position(players, rules, position.clone(), self.seed)?
+            }
+            None => GameState::new(players, rules, self.seed)?,
+        };
+        if let Some(started_ms) = self.started_ms {
+            state.start(started_ms);
+        }
+
+        for (index, record) in self.history.iter().take(turn_index).enumerate() {
+            let player_id = state
+                .player_id(record.player)
+                .ok_or(GameError::UnknownPlayer)?
+                .to_string();
+            // Imported games were not drawn from the seed, their racks come from the history.
+            state.set_rack(record.player, &record.rack)?;
+
+            // Challenges are decided by the recorded verdict instead of a dictionary.
+            let recorded_words: HashSet<String>;
+            let validator: &dyn WordValidator = match (&record.action, &record.evaluation) {
+                (TurnAction::Challenge, Some(evaluation)) => {
+                    recorded_words = evaluation
+                        .words
+                        .iter()
+                        .filter(|word| !evaluation.invalid_words.contains(word))
+                        .map(|word| word.to_lowercase())
+                        .collect();
+                    &recorded_words
+                }
+                _ => &AcceptAllWords,
+            };
+
+            let replayed = state.apply(
+                &player_id,
+                record.action.clone(),
+                validator,
+                record.timestamp_ms,
+            )?;
+
+            if replayed.score != record.score || replayed.drawn != record.drawn {
+                return Err(GameError::ReplayDiverged(index));
+            }
+
+            if let Some(replayed) = state.history.last_mut() {
+                *replayed = record.clone();
+            }
+        }
+
+        // Running out of time is not a turn, the flag falls again after the last one.
+        if turn_index == self.history.len()
+            && let Some(timed_out_ms) = self.timed_out_ms
+            && !state.check_time(timed_out_ms)
+        {
+            return Err(GameError::ReplayDiverged(turn_index));
+        }
+
+        Ok(state)
+    }
+
+    /// Takes back the last turn, meant for practice games. Team messages are kept.
+    pub fn undo(&mut self) -> GameResult<()> {
+        let Some(last) = self.history.len().checked_sub(1) else {
+            return Err(GameError::NothingToUndo);
+        };
+        let team_messages = std::mem::take(&mut self.team_messages);
+        *self = self.replay(last)?;
+        self.team_messages = team_messages;
+        Ok(())
+    }
+
+    /// Applies the action of the given player, resigning is possible out of turn.
+    pub fn apply(
+        &mut self,
+        player_id: &str,
+        action: TurnAction,
+        validator: &dyn WordValidator,
+        timestamp_ms: i64,
+    ) -> GameResult<&TurnRecord> {
+        if self.is_finished() {
+            return Err(GameError::GameIsFinished);
+        }
+
+        if self.check_time(timestamp_ms) {
+            return Err(GameError::OutOfTime);
+        }
+
+        let player = self.player_index(player_id)?;
+        if self.has_resigned(player) {
+            return Err(GameError::PlayerResigned);
+        }
+        if player != self.current_player && action != TurnAction::Resign {
+            return Err(GameError::NotYourTurn);
+        }
+
+        let current_player = self.current_player;
+        self.apply_action(player, action, validator, timestamp_ms)?;
+        self.update_clock(current_player, timestamp_ms);
+        if self.is_finished() {
+            self.charge_overtime();
+        }
+
+        Ok(&self.history[self.history.len() - 1])
+    }
+
+    fn apply_action(
+        &mut self,
+        player: u8,
+        action: TurnAction,
+        validator: &dyn WordValidator,
+        timestamp_ms: i64,
+    ) -> GameResult<&TurnRecord> {
+        match action {
+            TurnAction::Challenge => return self.challenge(player, validator, timestamp_ms),
+            TurnAction::Accept => return self.accept(player, timestamp_ms),
+            TurnAction::Resign => self.pending = None,
+            _ => {
+                if self
+                    .pending
+                    .as_ref()
+                    .is_some_and(|pending| self.ends_game(pending))
+                {
+                    return Err(GameError::PendingMoveUnresolved);
+                }
+                self.pending = None;
+            }
+        }
+
+        let rack = self.racks[self.rack_index(player)].tiles().to_vec();
+        let scoreless_turns = self.scoreless_turns;
+        let TurnResult {
+            evaluation,
+            score,
+            drawn,
+        } = match &action {
+            TurnAction::Place(board_move) => self.place(player, board_move, validator)?,
+            TurnAction::Pass => TurnResult::default(),
+            TurnAction::Exchange(tiles) => self.exchange(player, tiles)?,
+            TurnAction::Resign => TurnResult::default(),
+            // Handled before, they do not use up the turn.
+            TurnAction::Challenge | TurnAction::Accept => TurnResult::default(),
+        };
+
+        // Leaving the game is not a scoreless turn of the remaining players.
+        let resigned = action == TurnAction::Resign;
+        self.scores[player as usize] += score;
+        if score > 0 {
+            self.scoreless_turns = 0;
+        } else if !resigned {
+            self.scoreless_turns = self.scoreless_turns.saturating_add(1);
+        }
+
+        if let (TurnAction::Place(board_move), Some(evaluation)) = (&action, &evaluation)
+            && self.rules.challenge_mode != ChallengeMode::Void
+        {
+            self.pending = Some(PendingMove {
+                player,
+                board_move: board_move.clone(),
+                evaluation: evaluation.clone(),
+                score,
+                drawn: drawn.clone(),
+                scoreless_turns,
+            });
+        }
+
+        self.history.push(TurnRecord {
+            player,
+            action,
+            rack,
+            evaluation,
+            score,
+            drawn,
+            timestamp_ms,
+        });
+
+        if resigned {
+            self.resign(player)?;
+        } else if self.bag.is_empty()
+            && self.racks[self.rack_index(player)].is_empty()
+            && self.pending.is_none()
+        {
+            self.finish(GameEndReason::PlayedOut { player });
+        } else if self.scoreless_limit_reached() {
+            self.finish(GameEndReason::ScorelessTurns);
+        } else {
+            self.current_player = self.next_player(self.current_player);
+        }
+
+        Ok(&self.history[self.history.len() - 1])
+    }
+
+    /// A successful challenge takes the pending move back, the challenger keeps their turn.
+    ///
+    /// An unsuccessful challenge earns the challenged player the challenge bonus. In the double
+    /// challenge mode it also costs the challenger their turn, the turn passes on to the next player.
+    fn challenge(
+        &mut self,
+        player: u8,
+        validator: &dyn WordValidator,
+        timestamp_ms: i64,
+    ) -> GameResult<&TurnRecord> {
+        let pending = self.pending.take().ok_or(GameError::NoPendingMove)?;
+        let rack = self.racks[self.rack_index(player)].tiles().to_vec();
+
+        let mut evaluation = pending.evaluation.clone();
+        evaluation.check_words(validator);
+        let successful = !evaluation.is_valid();
+        if successful {
+            self.withdraw(&pending)?;
+        } else {
+            self.scores[pending.player as usize] += self.rules.challenge_bonus as i32;
+        }
+
+        self.history.push(TurnRecord {
+            player,
+            action: TurnAction::Challenge,
+            rack,
+            evaluation: Some(evaluation),
+            score: 0,
+            drawn: Vec::new(),
+            timestamp_ms,
+        });
+
+        if successful {
+            if self.scoreless_limit_reached() {
+                self.finish(GameEndReason::ScorelessTurns);
+            }
+        } else if self.ends_game(&pending) {
+            self.finish(GameEndReason::PlayedOut {
+                player: pending.player,
+            });
+        } else if self.rules.challenge_mode == ChallengeMode::Double {
+            self.current_player = self.next_player(player);
+        }
+
+        Ok(&self.history[self.history.len() - 1])
+    }
+
+    /// Accepting does not use up the turn, unless the pending move ends the game.
+    fn accept(&mut self, player: u8, timestamp_ms: i64) -> GameResult<&TurnRecord> {
+        let pending = self.pending.take().ok_or(GameError::NoPendingMove)?;
+        let rack = self.racks[self.rack_index(player)].tiles().to_vec();
+
+        self.history.push(TurnRecord {
+            player,
+            action: TurnAction::Accept,
+            rack,
+            evaluation: None,
+            score: 0,
+            drawn: Vec::new(),
+            timestamp_ms,
+        });
+
+        if self.ends_game(&pending) {
+            self.finish(GameEndReason::PlayedOut {
+                player: pending.player,
+            });
+        }
+
+        Ok(&self.history[self.history.len() - 1])
+    }
+
+    /// Whether the pending move used up the last tiles, so accepting it ends the game.
+    fn ends_game(&self, pending: &PendingMove) -> bool {
+        self.bag.is_empty() && self.racks[self.rack_index(pending.player)].is_empty()
+    }
+
+    /// Takes the pending move back, the drawn tiles return to the bag.
+    fn withdraw(&mut self, pending: &PendingMove) -> GameResult<()> {
+        self.board.remove_tiles(&pending.board_move)?;
+
+        let index = self.rack_index(pending.player);
+        let rack = &mut self.racks[index];
+        rack.remove_tiles(&pending.drawn)?;
+        self.bag.return_tiles(pending.drawn.iter().copied());
+        for part in &pending.board_move.parts {
+            rack.add(part.rack_tile())?;
+        }
+
+        self.scores[pending.player as usize] -= pending.score;
+        self.scoreless_turns = pending.scoreless_turns.saturating_add(1);
+        Ok(())
+    }
+
+    /// Team games and games with a single player left end, otherwise the player leaves the game
+    /// and their tiles go back into the bag.
+    fn resign(&mut self, player: u8) -> GameResult<()> {
+        self.resigned[player as usize] = true;
+        if self.rules.teams.is_some() || (self.active_player_count() as usize) < MIN_PLAYERS {
+            self.finish(GameEndReason::Resigned { player });
+            return Ok(());
+        }
+
+        let index = self.rack_index(player);
+        let tiles = self.racks[index].tiles().to_vec();
+        self.racks[index].remove_tiles(&tiles)?;
+        self.bag.return_tiles(tiles);
+
+        if player == self.current_player {
+            self.current_player = self.next_player(player);
+        }
+        Ok(())
+    }
+
+    /// Every player still in the game went without a score for the configured number of rounds.
+    fn scoreless_limit_reached(&self) -> bool {
+        let limit = self.rules.scoreless_round_limit as usize * self.active_player_count() as usize;
+        limit > 0 && self.scoreless_turns as usize >= limit
+    }
+
+    /// Puts exactly the given tiles on the player's rack, used to import games recorded elsewhere.
+    ///
+    /// If the bag lacks some of the tiles, all racks go back into the bag first
+    /// and the other players draw new tiles afterwards. Nothing changes if the rack
+    /// already holds the tiles, which lets replays restore imported racks.
+    pub(crate) fn set_rack(&mut self, player: u8, tiles: &[Tile]) -> GameResult<()> {
+        if player >= self.player_count() {
+            return Err(GameError::UnknownPlayer);
+        }
+        let index = self.rack_index(player);
+
+        let mut held = self.racks[index].tiles().to_vec();
+        let mut wanted = tiles.to_vec();
+        held.sort_by_key(|tile| *tile as u8);
+        wanted.sort_by_key(|tile| *tile as u8);
+        if held == wanted {
+            return Ok(());
+        }
+
+        let previous = self.racks[index].tiles().to_vec();
+        self.racks[index].remove_tiles(&previous)?;
+        self.bag.return_tiles(previous);
+
+        if self.bag.take(tiles).is_err() {
+            for rack in &mut self.racks {
+                let held = rack.tiles().to_vec();
+                rack.remove_tiles(&held)?;
+                self.bag.return_tiles(held);
+            }
+            self.bag.take(tiles)?;
+        }
+
+        for tile in tiles {
+            self.racks[index].add(*tile)?;
+        }
+
+        for (other, rack) in self.racks.iter_mut().enumerate() {
+            if other != index {
+                rack.refill(&mut self.bag);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn place(
+        &mut self,
+        player: u8,
+        board_move: &BoardMove,
+        validator: &dyn WordValidator,
+    ) -> GameResult<TurnResult> {
+        let index = self.rack_index(player);
+        let rack = &mut self.racks[index];
+        rack.validate_move(board_move)?;
+
+        let mut evaluation = self.board.evaluate(board_move)?;
+        evaluation.check_words(validator);
+        if self.rules.challenge_mode == ChallengeMode::Void && !evaluation.is_valid() {
+            return Err(GameError::InvalidWords(evaluation.invalid_words));
+        }
+
+        self.board.place_tiles(board_move)?;
+        rack.play(board_move)?;
+
+        let mut score = evaluation.score as i32;
+        if board_move.parts.len() == rack.capacity() as usize {
+            score += self.rules.bingo_bonus as i32;
+        }
+
+        let drawn = rack.refill(&mut self.bag);
+        Ok(TurnResult {
+            evaluation: Some(evaluation),
+            score,
+            drawn,
+        })
+    }
+
+    fn exchange(&mut self, player: u8, tiles: &[Tile]) -> GameResult<TurnResult> {
+        if tiles.is_empty() {
+            return Err(GameError::NothingToExchange);
+        }
+
+        let index = self.rack_index(player);
+        let rack = &mut self.racks[index];
+        let required = rack.capacity() as usize;
+        if self.bag.remaining() < required {
+            return Err(GameError::NotEnoughTilesInBag {
+                requested: required,
+                remaining: self.bag.remaining(),
+            });
+        }
+
+        let drawn = rack.exchange(tiles, &mut self.bag)?;
+        Ok(TurnResult {
+            drawn,
+            ..TurnResult::default()
+        })
+    }
+
+    /// Applies the standard rack penalties and ends the game.
+    ///
+    /// A player who played out gains the rack values of all other players, who each lose their own.
+    /// Shared team racks are charged to the first player of the team.
+    fn finish(&mut self, reason: GameEndReason) {
+        let language = self.rules.language;
+        let rack_values: Vec<i32> = self
+            .racks
+            .iter()
+            .map(|rack| rack.value(language) as i32)
+            .collect();
+
+        match reason {
+            GameEndReason::PlayedOut { player } => {
+                for (index, value) in rack_values.iter().enumerate() {
+                    self.scores[index] -= value;
+                    self.scores[player as usize] += value;
+                }
+            }
+            GameEndReason::ScorelessTurns => {
+                for (index, value) in rack_values.iter().enumerate() {
+                    self.scores[index] -= value;
+                }
+            }
+            GameEndReason::Resigned { .. } | GameEndReason::TimedOut { .. } => {}
+        }
+
+        self.status = GameStatus::Finished(reason);
+    }
+}
+
+/// The number of racks in play, teams sharing their rack hold one rack each.
+fn rack_count(rules: &GameRules, player_count: usize) -> usize {
+    match rules.teams {
+        Some(TeamRules {
+            rack: TeamRack::Shared,
+        }) => TeamRules::TEAM_COUNT as usize,
+        _ => player_count,
+    }
+}
+
+/// The player or team with the highest score, `None` on a tie.
+fn highest_score(scores: impl Iterator<Item = (u8, i32)>, excluded: Option<u8>) -> Option<u8> {
+    let mut scores = scores.filter(|(side, _)| Some(*side) != excluded);
+    let (mut winner, mut best) = scores.next()?;
+    let mut tied = false;
+    for (side, score) in scores {
+        if score > best {
+            (winner, best, tied) = (side, score, false);
+        } else if score == best {
+            tied = true;
+        }
+    }
+
+    (!tied).then_some(winner)
+}
+
+#[derive(Debug, Default)]
+struct TurnResult {
+    evaluation: Option<BoardMoveEvaluation>,
+    score: i32,
+    drawn: Vec<Tile>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::board_move::BoardMovePart;
+    use crate::game::board::coordinates::BoardCoords;
+    use crate::game::gcg;
+    use crate::game::rules::TimeControl;
+
+    const CAT: &str = "15/15/15/15/15/15/15/7CAT5/15/15/15/15/15/15/15 AEINRST/AEIOU?? XYZ 0";
+
+    fn players() -> Vec<String> {
+        vec!["a".to_string(), "b".to_string()]
+    }
+
+    fn from_cat(seed: u64) -> GameState {
+        let rules = GameRules::classic_english();
+        let position = Position::parse(CAT, &rules).unwrap();
+        GameState::from_position(players(), rules, position, seed).unwrap()
+    }
+
+    #[test]
+    fn positions_keep_the_bag_order() {
+        let state = from_cat(7);
+        assert_eq!(state.bag().tiles(), &[Tile::X, Tile::Y, Tile::Z]);
+        assert_eq!(state.position().format().unwrap(), CAT);
+    }
+
+    #[test]
+    fn undo_returns_to_the_starting_position() {
+        let mut state = from_cat(7);
+        state
+            .apply("a", TurnAction::Pass, &AcceptAllWords, 0)
+            .unwrap();
+        state.undo().unwrap();
+
+        assert_eq!(state.position().format().unwrap(), CAT);
+        assert!(state.history().is_empty());
+    }
+
+    fn timed_rules(time_control: TimeControl) -> GameRules {
+        GameRules {
+            time_control: Some(time_control),
+            ..GameRules::classic_english()
+        }
+    }
+
+    #[test]
+    fn the_first_turn_is_charged_once_the_game_started() {
+        let mut state = GameState::new(players(), timed_rules(TimeControl::blitz()), 7).unwrap();
+        state.start(0);
+        state
+            .apply("a", TurnAction::Pass, &AcceptAllWords, 10_000)
+            .unwrap();
+
+        assert_eq!(
+            state.clock(0).unwrap().remaining_ms,
+            300_000 - 10_000 + 5_000
+        );
+    }
+
+    #[test]
+    fn the_first_player_can_run_out_of_time() {
+        let mut state = GameState::new(players(), timed_rules(TimeControl::blitz()), 7).unwrap();
+        state.start(0);
+
+        assert!(!state.check_time(300_000));
+        assert!(state.check_time(300_001));
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::TimedOut { player: 0 })
+        );
+
+        let replayed = state.replay(state.history().len()).unwrap();
+        assert_eq!(replayed.status(), state.status());
+    }
+
+    #[test]
+    fn overtime_of_the_last_turn_is_charged() {
+        let time_control = TimeControl {
+            initial_ms: 60_000,
+            overtime_penalty: 10,
+            max_overtime_ms: 10 * 60_000,
+            ..TimeControl::default()
+        };
+        // With three players the game ending pass is made by the third one.
+        let players = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let mut state = GameState::new(players, timed_rules(time_control), 7).unwrap();
+        state.start(0);
+
+        let mut now_ms = 0;
+        while !state.is_finished() {
+            let player_id = state.current_player_id().to_string();
+            let mut probe = state.clone();
+            probe
+                .apply(
+                    &player_id,
+                    TurnAction::Pass,
+                    &AcceptAllWords,
+                    now_ms + 1_000,
+                )
+                .unwrap();
+            // The game ending pass takes three minutes.
+            now_ms += if probe.is_finished() { 180_000 } else { 1_000 };
+            state
+                .apply(&player_id, TurnAction::Pass, &AcceptAllWords, now_ms)
+                .unwrap();
+        }
+
+        let player = state.history().last().unwrap().player;
+        assert_eq!(player, 2);
+        let rack_value = state.rack(player).unwrap().value(state.rules().language) as i32;
+        assert_eq!(state.overtime_penalty(player), 30);
+        assert_eq!(state.score(player), Some(-rack_value - 30));
+
+        let exported = gcg::export(&state).unwrap();
+        let total = exported.lines().last().unwrap().rsplit(' ').next().unwrap();
+        assert_eq!(total, (-rack_value - 30).to_string());
+    }
+
+    #[test]
+    fn resigning_leaves_a_game_of_three() {
+        let players = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let mut state = GameState::new(players, GameRules::classic_english(), 7).unwrap();
+        let bag_size = state.bag().remaining();
+
+        state
+            .apply("b", TurnAction::Resign, &AcceptAllWords, 0)
+            .unwrap();
+        assert!(!state.is_finished());
+        assert!(state.rack(1).unwrap().is_empty());
+        assert_eq!(state.bag().remaining(), bag_size + 7);
+        assert_eq!(
+            state.apply("b", TurnAction::Resign, &AcceptAllWords, 0),
+            Err(GameError::PlayerResigned)
+        );
+
+        state
+            .apply("a", TurnAction::Pass, &AcceptAllWords, 0)
+            .unwrap();
+        assert_eq!(state.current_player(), 2);
+
+        state
+            .apply("c", TurnAction::Resign, &AcceptAllWords, 0)
+            .unwrap();
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::Resigned { player: 2 })
+        );
+        assert_eq!(state.winner(), Some(0));
+    }
+
+    #[test]
+    fn scoreless_limit_counts_rounds() {
+        let players = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let mut state = GameState::new(players, GameRules::classic_english(), 7).unwrap();
+
+        for _ in 0..11 {
+            let player_id = state.current_player_id().to_string();
+            state
+                .apply(&player_id, TurnAction::Pass, &AcceptAllWords, 0)
+                .unwrap();
+        }
+        assert!(!state.is_finished());
+
+        state
+            .apply("d", TurnAction::Pass, &AcceptAllWords, 0)
+            .unwrap();
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::ScorelessTurns)
+        );
+    }
+
+    fn team_players() -> Vec<String> {
+        ["a", "b", "c", "d"].map(String::from).to_vec()
+    }
+
+    fn team_rules(rack: TeamRack) -> GameRules {
+        GameRules {
+            teams: Some(TeamRules { rack }),
+            ..GameRules::classic_english()
+        }
+    }
+
+    /// CAT across the anchor with the given racks and an empty bag.
+    fn team_game(rack: TeamRack, racks: &str) -> GameState {
+        let rules = team_rules(rack);
+        let text = format!("15/15/15/15/15/15/15/7CAT5/15/15/15/15/15/15/15 {racks} - 0");
+        let position = Position::parse(&text, &rules).unwrap();
+        GameState::from_position(team_players(), rules, position, 7).unwrap()
+    }
+
+    /// Plays an S in the row of CAT, CATS is worth 6 points.
+    fn play_s(state: &mut GameState, player_id: &str, x: u8) {
+        let coords = BoardCoords::from_x_y(x, 7, 15).unwrap();
+        let board_move = BoardMove::new(vec![BoardMovePart::new(coords, Tile::S)]);
+        state
+            .apply(player_id, TurnAction::Place(board_move), &AcceptAllWords, 0)
+            .unwrap();
+    }
+
+    fn pass(state: &mut GameState, player_id: &str) {
+        state
+            .apply(player_id, TurnAction::Pass, &AcceptAllWords, 0)
+            .unwrap();
+    }
+
+    #[test]
+    fn team_members_share_or_keep_their_racks() {
+        let shared = GameState::new(team_players(), team_rules(TeamRack::Shared), 7).unwrap();
+        assert_eq!(shared.rack_count(), 2);
+        assert_eq!(shared.rack_index(2), 0);
+        assert_eq!(shared.rack_index(3), 1);
+        assert_eq!(shared.rack(0), shared.rack(2));
+        assert_eq!(shared.bag().remaining(), 100 - 2 * 7);
+
+        let separate = GameState::new(team_players(), team_rules(TeamRack::Separate), 7).unwrap();
+        assert_eq!(separate.rack_count(), 4);
+        assert_eq!(separate.rack_index(2), 2);
+        assert_eq!(separate.bag().remaining(), 100 - 4 * 7);
+    }
+
+    #[test]
+    fn teams_score_the_sum_of_their_members() {
+        let mut state = team_game(TeamRack::Separate, "SE/E/SE/E");
+        play_s(&mut state, "a", 10);
+        pass(&mut state, "b");
+        play_s(&mut state, "c", 6);
+
+        assert_eq!(state.team(2), Some(0));
+        assert_eq!(
+            state.team_score(0),
+            Some(state.score(0).unwrap() + state.score(2).unwrap())
+        );
+        assert_eq!(state.team_score(1), Some(0));
+        assert_eq!(state.team_score(2), None);
+        assert_eq!(state.winning_team(), None);
+
+        state
+            .apply("b", TurnAction::Resign, &AcceptAllWords, 0)
+            .unwrap();
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::Resigned { player: 1 })
+        );
+        assert_eq!(state.winning_team(), Some(0));
+        assert_eq!(state.winner(), None);
+    }
+
+    #[test]
+    fn shared_racks_are_penalized_once_per_team() {
+        let mut state = team_game(TeamRack::Shared, "S/QZ");
+        pass(&mut state, "a");
+        pass(&mut state, "b");
+        play_s(&mut state, "c", 10);
+
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::PlayedOut { player: 2 })
+        );
+        assert_eq!(state.score(1), Some(-20));
+        assert_eq!(state.score(2), Some(6 + 20));
+        assert_eq!(state.team_score(0), Some(26));
+        assert_eq!(state.team_score(1), Some(-20));
+        assert_eq!(state.winning_team(), Some(0));
+    }
+
+    #[test]
+    fn separate_racks_end_the_game_when_one_member_plays_out() {
+        let mut state = team_game(TeamRack::Separate, "S/Q/A/Z");
+        play_s(&mut state, "a", 10);
+
+        assert_eq!(
+            state.status(),
+            GameStatus::Finished(GameEndReason::PlayedOut { player: 0 })
+        );
+        assert_eq!(state.score(0), Some(6 + 21));
+        assert_eq!(state.team_score(0), Some(27 - 1));
+        assert_eq!(state.team_score(1), Some(-20));
+    }
+
+    #[test]
+    fn team_messages_stay_within_the_team() {
+        let mut state = GameState::new(team_players(), team_rules(TeamRack::Shared), 7).unwrap();
+        state
+            .post_team_message("c", TeamMessageContent::Text("Hold the S".to_string()), 0)
+            .unwrap();
+
+        let messages: Vec<_> = state.team_messages(0).collect();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].player, 2);
+        assert_eq!(state.team_messages(1).count(), 0);
+
+        let encoded = bincode::encode_to_vec(&state, bincode::config::standard()).unwrap();
+        let (decoded, _): (GameState, _) =
+            bincode::decode_from_slice(&encoded, bincode::config::standard()).unwrap();
+        assert_eq!(decoded.team_messages(0).count(), 1);
+
+        let mut solo = GameState::new(players(), GameRules::classic_english(), 7).unwrap();
+        assert_eq!(
+            solo.post_team_message("a", TeamMessageContent::Text(String::new()), 0)
+                .unwrap_err(),
+            GameError::NotATeamGame
+        );
+    }
 }
@@ -0,0 +1,18 @@
+use crate::game::board::board_move::BoardMove;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum TeamMessageContent {
+    Text(String),
+    /// A move proposed to the partner, it is not validated.
+    Suggestion(BoardMove),
+}
+
+/// A message only the team members of the sender can see.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TeamMessage {
+    pub player: u8,
+    pub team: u8,
+    pub content: TeamMessageContent,
+    pub timestamp_ms: i64,
+}
@@ -0,0 +1,31 @@
+use crate::game::board::board_move::{BoardMove, BoardMoveEvaluation};
+use crate::game::board::tile::Tile;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum TurnAction {
+    Place(BoardMove),
+    Pass,
+    /// The tiles from the player's rack that go back into the bag.
+    Exchange(Vec<Tile>),
+    Resign,
+    /// Challenges the words of the opponent's pending move.
+    Challenge,
+    /// Accepts the opponent's pending move without challenging it.
+    Accept,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct TurnRecord {
+    pub player: u8,
+    pub action: TurnAction,
+    /// The player's rack before the turn.
+    pub rack: Vec<Tile>,
+    pub evaluation: Option<BoardMoveEvaluation>,
+    /// The total score of the turn, including the bingo bonus.
+    pub score: i32,
+    /// The tiles the player drew from the bag at the end of the turn.
+    pub drawn: Vec<Tile>,
+    /// Unix timestamp in milliseconds.
+    pub timestamp_ms: i64,
+}
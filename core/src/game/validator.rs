@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+/// Looks up whether a word is allowed to be played.
+///
+/// Words are passed in lowercase, blanks are already resolved to their letters.
+pub trait WordValidator {
+    fn is_valid_word(&self, word: &str) -> bool;
+}
+
+/// Accepts every word, for practice games and for replaying already validated turns.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AcceptAllWords;
+
+impl WordValidator for AcceptAllWords {
+    fn is_valid_word(&self, _word: &str) -> bool {
+        true
+    }
+}
+
+impl WordValidator for HashSet<String> {
+    fn is_valid_word(&self, word: &str) -> bool {
+        self.contains(word)
+    }
+}
@@ -0,0 +1,263 @@
+//! What a player or spectator may see of a game, meant to be sent to the clients.
+//!
+//! The full `GameState` would reveal the racks of the opponents and the order of the bag.
+use crate::game::board::board_move::{BoardMove, BoardMoveEvaluation};
+use crate::game::board::tile::Tile;
+use crate::game::board::Board;
+use crate::game::error::{GameError, GameResult};
+use crate::game::rules::GameRules;
+use crate::game::state::{GameState, GameStatus, PlayerClock};
+use crate::game::team::TeamMessage;
+use crate::game::turn::{TurnAction, TurnRecord};
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum PublicTurnAction {
+    Place(BoardMove),
+    Pass,
+    /// Only the number of exchanged tiles is public.
+    Exchange {
+        count: u8,
+    },
+    Resign,
+    Challenge,
+    Accept,
+}
+
+/// A turn without the rack of the player and the tiles they drew.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct PublicTurnRecord {
+    pub player: u8,
+    pub action: PublicTurnAction,
+    pub evaluation: Option<BoardMoveEvaluation>,
+    pub score: i32,
+    pub timestamp_ms: i64,
+}
+
+impl From<&TurnRecord> for PublicTurnRecord {
+    fn from(record: &TurnRecord) -> Self {
+        let action = match &record.action {
+            TurnAction::Place(board_move) => PublicTurnAction::Place(board_move.clone()),
+            TurnAction::Pass => PublicTurnAction::Pass,
+            TurnAction::Exchange(tiles) => PublicTurnAction::Exchange {
+                count: tiles.len() as u8,
+            },
+            TurnAction::Resign => PublicTurnAction::Resign,
+            TurnAction::Challenge => PublicTurnAction::Challenge,
+            TurnAction::Accept => PublicTurnAction::Accept,
+        };
+
+        Self {
+            player: record.player,
+            action,
+            evaluation: record.evaluation.clone(),
+            score: record.score,
+            timestamp_ms: record.timestamp_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct PublicPendingMove {
+    pub player: u8,
+    pub board_move: BoardMove,
+    pub evaluation: BoardMoveEvaluation,
+    pub score: i32,
+}
+
+/// The game as seen by someone without a rack, every rack is hidden.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct SpectatorView {
+    pub players: Vec<String>,
+    pub rules: GameRules,
+    pub board: Board,
+    /// The number of tiles on every rack, indexed like the racks of a `Position`.
+    pub rack_sizes: Vec<u8>,
+    pub bag_size: usize,
+    /// The tiles the viewer can not see, the bag and the hidden racks, sorted by tile.
+    pub unseen: Vec<Tile>,
+    pub scores: Vec<i32>,
+    pub current_player: u8,
+    pub history: Vec<PublicTurnRecord>,
+    pub pending: Option<PublicPendingMove>,
+    pub clocks: Vec<PlayerClock>,
+    pub turn_started_ms: Option<i64>,
+    pub status: GameStatus,
+}
+
+impl SpectatorView {
+    pub fn new(state: &GameState) -> Self {
+        let racks: Vec<&[Tile]> = (0..state.rack_count())
+            .filter_map(|index| state.rack(index))
+            .map(|rack| rack.tiles())
+            .collect();
+
+        let mut unseen = state.bag().tiles().to_vec();
+        unseen.extend(racks.iter().copied().flatten());
+        unseen.sort_by_key(|tile| *tile as u8);
+
+        Self {
+            players: state.players().to_vec(),
+            rules: state.rules().clone(),
+            board: state.board().clone(),
+            rack_sizes: racks.iter().map(|rack| rack.len() as u8).collect(),
+            bag_size: state.bag().remaining(),
+            unseen,
+            scores: (0..state.player_count())
+                .filter_map(|player| state.score(player))
+                .collect(),
+            current_player: state.current_player(),
+            history: state.history().iter().map(PublicTurnRecord::from).collect(),
+            pending: state.pending_move().map(|pending| PublicPendingMove {
+                player: pending.player,
+                board_move: pending.board_move.clone(),
+                evaluation: pending.evaluation.clone(),
+                score: pending.score,
+            }),
+            clocks: (0..state.player_count())
+                .filter_map(|player| state.clock(player).copied())
+                .collect(),
+            turn_started_ms: state.turn_started_ms(),
+            status: state.status(),
+        }
+    }
+}
+
+/// The game as seen by one of the players, only their own rack and team messages are visible.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct PlayerView {
+    pub player: u8,
+    pub rack: Vec<Tile>,
+    pub team_messages: Vec<TeamMessage>,
+    /// The unseen tiles do not include the player's rack.
+    pub game: SpectatorView,
+}
+
+impl PlayerView {
+    pub fn new(state: &GameState, player: u8) -> GameResult<Self> {
+        let rack = state.rack(player).ok_or(GameError::UnknownPlayer)?.tiles();
+
+        let mut game = SpectatorView::new(state);
+        for tile in rack {
+            if let Some(index) = game.unseen.iter().position(|unseen| unseen == tile) {
+                game.unseen.remove(index);
+            }
+        }
+
+        let team_messages = match state.team(player) {
+            Some(team) => state.team_messages(team).cloned().collect(),
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            player,
+            rack: rack.to_vec(),
+            team_messages,
+            game,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::position::Position;
+    use crate::game::rules::{TeamRack, TeamRules};
+    use crate::game::team::TeamMessageContent;
+    use crate::game::validator::AcceptAllWords;
+
+    const BOARD: &str = "15/15/15/15/15/15/15/7CAT5/15/15/15/15/15/15/15";
+
+    fn players() -> Vec<String> {
+        vec!["a".to_string(), "b".to_string()]
+    }
+
+    fn state(racks: &str, bag: &str) -> GameState {
+        let rules = GameRules::classic_english();
+        let position = Position::parse(&format!("{BOARD} {racks} {bag} 0"), &rules).unwrap();
+        GameState::from_position(players(), rules, position, 7).unwrap()
+    }
+
+    fn sorted_tiles(text: &str) -> Vec<Tile> {
+        let mut tiles: Vec<Tile> = text
+            .chars()
+            .map(|char| Tile::try_from(char).unwrap())
+            .collect();
+        tiles.sort_by_key(|tile| *tile as u8);
+        tiles
+    }
+
+    #[test]
+    fn spectators_see_no_rack() {
+        let state = state("AEINRST/QU", "XYZ");
+        let view = SpectatorView::new(&state);
+
+        assert_eq!(view.rack_sizes, vec![7, 2]);
+        assert_eq!(view.bag_size, 3);
+        assert_eq!(view.unseen, sorted_tiles("AEINRSTQUXYZ"));
+    }
+
+    #[test]
+    fn players_see_only_their_own_rack() {
+        let state = state("AEINRST/QU", "XYZ");
+
+        let first = PlayerView::new(&state, 0).unwrap();
+        assert_eq!(first.rack, state.rack(0).unwrap().tiles());
+        assert_eq!(first.game.rack_sizes, vec![7, 2]);
+        assert_eq!(first.game.unseen, sorted_tiles("QUXYZ"));
+
+        let second = PlayerView::new(&state, 1).unwrap();
+        assert_eq!(second.rack, vec![Tile::Q, Tile::U]);
+        assert_eq!(second.game.unseen, sorted_tiles("AEINRSTXYZ"));
+
+        assert_eq!(
+            PlayerView::new(&state, 2).unwrap_err(),
+            GameError::UnknownPlayer
+        );
+    }
+
+    #[test]
+    fn the_bag_order_is_not_exposed() {
+        let config = bincode::config::standard();
+        let forward = SpectatorView::new(&state("AEINRST/QU", "XYZ"));
+        let backward = SpectatorView::new(&state("AEINRST/QU", "ZYX"));
+
+        assert_eq!(
+            bincode::encode_to_vec(&forward, config).unwrap(),
+            bincode::encode_to_vec(&backward, config).unwrap()
+        );
+    }
+
+    #[test]
+    fn exchanged_tiles_are_hidden() {
+        let mut state = state("AEINRST/QU", "BCDEFGHLMOP");
+        let exchange = TurnAction::Exchange(vec![Tile::A, Tile::E]);
+        state.apply("a", exchange, &AcceptAllWords, 0).unwrap();
+
+        let view = SpectatorView::new(&state);
+        assert_eq!(
+            view.history[0].action,
+            PublicTurnAction::Exchange { count: 2 }
+        );
+    }
+
+    #[test]
+    fn team_messages_only_reach_teammates() {
+        let rules = GameRules {
+            teams: Some(TeamRules {
+                rack: TeamRack::Separate,
+            }),
+            ..GameRules::classic_english()
+        };
+        let players = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let mut state = GameState::new(players, rules, 7).unwrap();
+        state
+            .post_team_message("a", TeamMessageContent::Text("Keep the S".to_string()), 0)
+            .unwrap();
+
+        assert_eq!(PlayerView::new(&state, 0).unwrap().team_messages.len(), 1);
+        assert_eq!(PlayerView::new(&state, 2).unwrap().team_messages.len(), 1);
+        assert!(PlayerView::new(&state, 1).unwrap().team_messages.is_empty());
+        assert!(PlayerView::new(&state, 3).unwrap().team_messages.is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::game::board::tile::alphabet::Alphabet;
+use crate::game::board::tile::Tile;
+use crate::game::validator::WordValidator;
+use crate::types::language::Language;
+
+/// A word list that can be walked tile by tile, used by the move generator.
+pub trait WordGraph {
+    type Node: Copy;
+
+    /// The language whose alphabet splits words into tiles.
+    fn language(&self) -> Language;
+
+    fn root(&self) -> Self::Node;
+
+    fn child(&self, node: Self::Node, tile: Tile) -> Option<Self::Node>;
+
+    fn children(&self, node: Self::Node) -> impl Iterator<Item = (Tile, Self::Node)> + '_;
+
+    /// Whether the path from the root to this node spells a complete word.
+    fn is_word(&self, node: Self::Node) -> bool;
+
+    /// Follows the given tiles from the node, `None` if the path leaves the graph.
+    fn walk(&self, node: Self::Node, tiles: &[Tile]) -> Option<Self::Node> {
+        tiles
+            .iter()
+            .try_fold(node, |node, tile| self.child(node, *tile))
+    }
+
+    fn contains(&self, tiles: &[Tile]) -> bool {
+        self.walk(self.root(), tiles)
+            .is_some_and(|node| self.is_word(node))
+    }
+}
+
+/// A simple prefix tree, good enough for small word lists and tests.
+#[derive(Debug, Clone)]
+pub struct WordTrie {
+    nodes: Vec<TrieNode>,
+    language: Language,
+}
+
+#[derive(Debug, Default, Clone)]
+struct TrieNode {
+    /// Sorted by tile.
+    edges: Vec<(Tile, u32)>,
+    is_word: bool,
+}
+
+impl WordTrie {
+    pub fn new(language: Language) -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            language,
+        }
+    }
+
+    /// Words with letters outside the language's alphabet are skipped.
+    pub fn from_words<'a>(language: Language, words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut trie = Self::new(language);
+        for word in words {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    pub fn insert(&mut self, word: &str) {
+        let Some(tiles) = Alphabet::for_language(self.language).word_tiles(word) else {
+            return;
+        };
+
+        let mut node = 0usize;
+        for tile in tiles {
+            let edges = &self.nodes[node].edges;
+            node = match edges.binary_search_by_key(&(tile as u8), |(edge, _)| *edge as u8) {
+                Ok(index) => edges[index].1 as usize,
+                Err(index) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(TrieNode::default());
+                    self.nodes[node].edges.insert(index, (tile, child as u32));
+                    child
+                }
+            };
+        }
+        self.nodes[node].is_word = true;
+    }
+}
+
+impl WordGraph for WordTrie {
+    type Node = u32;
+
+    fn language(&self) -> Language {
+        self.language
+    }
+
+    fn root(&self) -> u32 {
+        0
+    }
+
+    fn child(&self, node: u32, tile: Tile) -> Option<u32> {
+        let edges = &self.nodes[node as usize].edges;
+        edges
+            .binary_search_by_key(&(tile as u8), |(edge, _)| *edge as u8)
+            .ok()
+            .map(|index| edges[index].1)
+    }
+
+    fn children(&self, node: u32) -> impl Iterator<Item = (Tile, u32)> + '_ {
+        self.nodes[node as usize].edges.iter().copied()
+    }
+
+    fn is_word(&self, node: u32) -> bool {
+        self.nodes[node as usize].is_word
+    }
+}
+
+/// Every word graph validates the words it contains.
+impl<G: WordGraph> WordValidator for G {
+    fn is_valid_word(&self, word: &str) -> bool {
+        Alphabet::for_language(self.language())
+            .word_tiles(word)
+            .is_some_and(|tiles| self.contains(&tiles))
+    }
+}
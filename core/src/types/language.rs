@@ -1,7 +1,32 @@
+use crate::game::language::{self, LanguageDefinition};
 use bincode::{Decode, Encode};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
-pub enum Language {
-    English,
-    German,
+/// Declares every language together with its definition, so the list exists only once.
+macro_rules! languages {
+    ($($language:ident => $definition:path),+ $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+        pub enum Language {
+            $($language,)+
+        }
+
+        impl Language {
+            pub const ALL: [Language; [$(Language::$language),+].len()] =
+                [$(Language::$language),+];
+
+            /// The tiles, values and dictionary sources of the language.
+            pub fn definition(&self) -> &'static LanguageDefinition {
+                match self {
+                    $(Language::$language => &$definition,)+
+                }
+            }
+        }
+    };
+}
+
+languages! {
+    English => language::ENGLISH,
+    German => language::GERMAN,
+    French => language::FRENCH,
+    Spanish => language::SPANISH,
+    Dutch => language::DUTCH,
 }
@@ -0,0 +1,193 @@
+use crate::dawg::error::{DawgError, DawgResult};
+use bincode::{Decode, Encode};
+use std::collections::HashMap;
+use wabble_core::game::board::tile::Tile;
+use wabble_core::game::word_graph::{WordGraph, WordTrie};
+use wabble_core::types::language::Language;
+
+pub mod error;
+
+const TILE_BITS: u32 = 6;
+const TILE_MASK: u32 = (1 << TILE_BITS) - 1;
+const LAST_EDGE: u32 = 1 << TILE_BITS;
+const IS_WORD: u32 = 1 << (TILE_BITS + 1);
+const CHILD_SHIFT: u32 = TILE_BITS + 2;
+const MAX_EDGES: usize = 1 << (32 - CHILD_SHIFT);
+
+/// A minimized word graph, identical suffixes of different words share their nodes.
+///
+/// Every node is a run of edges, each edge is packed into a `u32`:
+/// 6 bits for the tile, 1 bit marking the last edge of the node, 1 bit for whether
+/// the edge completes a word and 24 bits for the index of the child node's first edge.
+/// Index 0 is reserved for nodes without children.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct WabbleDawg {
+    edges: Vec<u32>,
+    root: u32,
+    language: Language,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DawgNode {
+    first_edge: u32,
+    is_word: bool,
+}
+
+impl WabbleDawg {
+    /// Words with letters outside the language's alphabet are skipped.
+    pub fn from_words<'a>(
+        language: Language,
+        words: impl IntoIterator<Item = &'a str>,
+    ) -> DawgResult<Self> {
+        Self::from_trie(&WordTrie::from_words(language, words))
+    }
+
+    /// Fails if the minimized graph has more edges than the child index can address.
+    pub fn from_trie(trie: &WordTrie) -> DawgResult<Self> {
+        let mut builder = DawgBuilder {
+            trie,
+            register: HashMap::new(),
+            edges: vec![LAST_EDGE],
+        };
+        let root = builder.add(trie.root())?;
+
+        Ok(Self {
+            edges: builder.edges,
+            root,
+            language: trie.language(),
+        })
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn node_edges(&self, node: DawgNode) -> impl Iterator<Item = u32> + '_ {
+        let edges = if node.first_edge == 0 {
+            &[][..]
+        } else {
+            &self.edges[node.first_edge as usize..]
+        };
+
+        let mut done = false;
+        edges.iter().copied().take_while(move |edge| {
+            let take = !done;
+            done = edge & LAST_EDGE != 0;
+            take
+        })
+    }
+}
+
+fn edge_tile(edge: u32) -> Option<Tile> {
+    Tile::try_from((edge & TILE_MASK) as u8).ok()
+}
+
+fn edge_target(edge: u32) -> DawgNode {
+    DawgNode {
+        first_edge: edge >> CHILD_SHIFT,
+        is_word: edge & IS_WORD != 0,
+    }
+}
+
+impl WordGraph for WabbleDawg {
+    type Node = DawgNode;
+
+    fn language(&self) -> Language {
+        self.language
+    }
+
+    fn root(&self) -> DawgNode {
+        DawgNode {
+            first_edge: self.root,
+            is_word: false,
+        }
+    }
+
+    fn child(&self, node: DawgNode, tile: Tile) -> Option<DawgNode> {
+        self.node_edges(node)
+            .find(|edge| edge & TILE_MASK == tile as u32)
+            .map(edge_target)
+    }
+
+    fn children(&self, node: DawgNode) -> impl Iterator<Item = (Tile, DawgNode)> + '_ {
+        self.node_edges(node)
+            .filter_map(|edge| Some((edge_tile(edge)?, edge_target(edge))))
+    }
+
+    fn is_word(&self, node: DawgNode) -> bool {
+        node.is_word
+    }
+}
+
+struct DawgBuilder<'a> {
+    trie: &'a WordTrie,
+    /// Already written nodes by their edges, used to share identical nodes.
+    register: HashMap<Vec<u32>, u32>,
+    edges: Vec<u32>,
+}
+
+impl DawgBuilder<'_> {
+    /// Writes the node after all its children, returns the index of its first edge.
+    /// The children of trie nodes are sorted by tile.
+    fn add(&mut self, node: u32) -> DawgResult<u32> {
+        let trie = self.trie;
+        let mut edges = Vec::new();
+        for (tile, child) in trie.children(node) {
+            let first_edge = self.add(child)?;
+            let is_word = if trie.is_word(child) { IS_WORD } else { 0 };
+            edges.push((first_edge << CHILD_SHIFT) | is_word | tile as u32);
+        }
+
+        let Some(last) = edges.last_mut() else {
+            return Ok(0);
+        };
+        *last |= LAST_EDGE;
+
+        if let Some(index) = self.register.get(&edges) {
+            return Ok(*index);
+        }
+
+        let index = self.edges.len();
+        if index + edges.len() > MAX_EDGES {
+            return Err(DawgError::TooManyEdges(index + edges.len()));
+        }
+        self.edges.extend_from_slice(&edges);
+        self.register.insert(edges, index as u32);
+        Ok(index as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wabble_core::game::validator::WordValidator;
+
+    const WORDS: [&str; 5] = ["cat", "cats", "bat", "bats", "at"];
+
+    #[test]
+    fn contains_the_same_words_as_the_trie() {
+        let trie = WordTrie::from_words(Language::English, WORDS);
+        let dawg = WabbleDawg::from_trie(&trie).unwrap();
+
+        for word in WORDS {
+            assert_eq!(dawg.is_valid_word(word), trie.is_valid_word(word), "{word}");
+        }
+        for word in ["ca", "cast", "bat s", "a"] {
+            assert!(!dawg.is_valid_word(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn shares_common_suffixes() {
+        let dawg = WabbleDawg::from_words(Language::English, ["cats", "bats", "rats"]).unwrap();
+        // The reserved edge, c b r, a, t and s.
+        assert_eq!(dawg.edge_count(), 7);
+    }
+
+    #[test]
+    fn splits_words_with_the_language_alphabet() {
+        let dawg = WabbleDawg::from_words(Language::Spanish, ["chorro"]).unwrap();
+        assert!(dawg.contains(&[Tile::CH, Tile::O, Tile::RR, Tile::O]));
+        assert!(!dawg.contains(&[Tile::C, Tile::H, Tile::O, Tile::R, Tile::R, Tile::O]));
+    }
+}
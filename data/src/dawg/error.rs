@@ -0,0 +1,7 @@
+pub type DawgResult<T> = Result<T, DawgError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DawgError {
+    #[error("Word graph has {0} edges, the child index only addresses 2^24")]
+    TooManyEdges(usize),
+}
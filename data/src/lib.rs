@@ -1,13 +1,28 @@
+use crate::dawg::WabbleDawg;
 use bincode::{Decode, Encode};
 use std::collections::HashMap;
+use wabble_core::game::validator::WordValidator;
 use wabble_core::types::language::Language;
 
+pub mod dawg;
 #[cfg(feature = "parse")]
 pub mod parse;
 
 #[derive(Debug, Default, Encode, Decode)]
 pub struct WabbleData {
     pub dictionaries: HashMap<Language, WabbleDictionary>,
+    /// Built from the dictionary words, used for move generation.
+    pub word_graphs: HashMap<Language, WabbleDawg>,
+}
+
+impl WabbleData {
+    pub fn dictionary(&self, language: Language) -> Option<&WabbleDictionary> {
+        self.dictionaries.get(&language)
+    }
+
+    pub fn word_graph(&self, language: Language) -> Option<&WabbleDawg> {
+        self.word_graphs.get(&language)
+    }
 }
 
 #[derive(Debug, Default, Encode, Decode)]
@@ -18,6 +33,10 @@ impl WabbleDictionary {
         Self(HashMap::new())
     }
 
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
     pub fn has_word(&self, word: impl AsRef<str>) -> bool {
         self.0.contains_key(word.as_ref())
     }
@@ -35,6 +54,12 @@ impl WabbleDictionary {
     }
 }
 
+impl WordValidator for WabbleDictionary {
+    fn is_valid_word(&self, word: &str) -> bool {
+        self.has_word(word)
+    }
+}
+
 #[derive(Debug, Default, Encode, Decode)]
 pub struct WabbleDictionaryEntry {
     /// Definitions for each part of speech.
@@ -1,5 +1,6 @@
-use crate::parse::dictionary::parse_dictionary;
-use crate::parse::error::ParseResult;
+use crate::dawg::WabbleDawg;
+use crate::parse::dictionary::{discover_sources, parse_dictionary};
+use crate::parse::error::{ParseError, ParseResult};
 use crate::WabbleData;
 use std::path::Path;
 use wabble_core::types::language::Language;
@@ -8,15 +9,36 @@ pub mod dictionary;
 pub mod error;
 mod kaikki;
 
-pub fn parse_data(resources: &Path) -> ParseResult<WabbleData> {
-    let english_dictionary = parse_dictionary(resources, Language::English)?;
-    let german_dictionary = parse_dictionary(resources, Language::German)?;
+/// Languages every build of the data has to contain, missing sources fail the build.
+const REQUIRED_LANGUAGES: [Language; 2] = [Language::English, Language::German];
 
+/// Other languages are only built when their whitelist and Kaikki file are in the resources.
+pub fn parse_data(resources: &Path) -> ParseResult<WabbleData> {
     let mut data = WabbleData::default();
-    data.dictionaries
-        .insert(Language::English, english_dictionary);
-    data.dictionaries
-        .insert(Language::German, german_dictionary);
+    for language in Language::ALL {
+        let sources = match discover_sources(resources, language) {
+            Ok(sources) => sources,
+            Err(ParseError::NoKaikkiFile(_) | ParseError::NoWhitelistFile(_))
+                if !REQUIRED_LANGUAGES.contains(&language) =>
+            {
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+
+        let dictionary = parse_dictionary(&sources, language)?;
+        data.dictionaries.insert(language, dictionary);
+    }
+
+    for (language, dictionary) in &data.dictionaries {
+        let word_graph = WabbleDawg::from_words(*language, dictionary.words())?;
+        println!(
+            "{:?}: word graph with {} edges",
+            language,
+            word_graph.edge_count()
+        );
+        data.word_graphs.insert(*language, word_graph);
+    }
 
     Ok(data)
 }
@@ -8,12 +8,36 @@ use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use wabble_core::types::language::Language;
 
-pub fn parse_dictionary(resources: &Path, language: Language) -> ParseResult<WabbleDictionary> {
-    let word_whitelist = word_whitelist(resources, language)?;
+/// The files a dictionary is built from.
+pub struct DictionarySources {
+    pub whitelist_path: PathBuf,
+    pub kaikki_path: PathBuf,
+}
+
+/// Looks for the whitelist and Kaikki file of the language in the resources directory.
+pub fn discover_sources(resources: &Path, language: Language) -> ParseResult<DictionarySources> {
+    let whitelist_path = resources.join(language.definition().whitelist_file);
+    if !whitelist_path.is_file() {
+        return Err(ParseError::NoWhitelistFile(language));
+    }
+
     let kaikki_path =
         kaikki_path(resources, language)?.ok_or(ParseError::NoKaikkiFile(language))?;
+    Ok(DictionarySources {
+        whitelist_path,
+        kaikki_path,
+    })
+}
 
-    let kaikki_file = std::fs::File::open(&kaikki_path)?;
+/// Words are stored the way they are spelled with the language's tiles.
+pub fn parse_dictionary(
+    sources: &DictionarySources,
+    language: Language,
+) -> ParseResult<WabbleDictionary> {
+    let definition = language.definition();
+    let word_whitelist = word_whitelist_txt(&sources.whitelist_path, language)?;
+
+    let kaikki_file = std::fs::File::open(&sources.kaikki_path)?;
     let file_size = kaikki_file.metadata()?.len();
     let buf_reader = BufReader::new(kaikki_file);
 
@@ -36,9 +60,10 @@ pub fn parse_dictionary(resources: &Path, language: Language) -> ParseResult<Wab
         bytes_read += line.len() as u64 + 1; // +1 for newline
         pb.set_position(bytes_read);
 
-        let kaikki_entry: KaikkiEntry = serde_json::from_str(&line)?;
+        let mut kaikki_entry: KaikkiEntry = serde_json::from_str(&line)?;
         processed += 1;
 
+        kaikki_entry.word = definition.normalize_word(&kaikki_entry.word);
         if word_whitelist.contains(&kaikki_entry.word) {
             kaikki_entry.add_to_dictionary(&mut dictionary);
             accepted += 1;
@@ -54,12 +79,7 @@ pub fn parse_dictionary(resources: &Path, language: Language) -> ParseResult<Wab
 }
 
 fn kaikki_path(resources: &Path, language: Language) -> ParseResult<Option<PathBuf>> {
-    let language_name = match language {
-        Language::English => "English",
-        Language::German => "Deutsch",
-    };
-
-    let pattern = kaikki_regex(language_name);
+    let pattern = kaikki_regex(language.definition().kaikki_name);
     let re = Regex::new(&pattern)?;
 
     Ok(std::fs::read_dir(resources)?
@@ -77,18 +97,12 @@ fn kaikki_regex(language_name: &str) -> String {
     format!(r"^kaikki\.org-dictionary-{language_name}\.jsonl$")
 }
 
-fn word_whitelist(resources: &Path, language: Language) -> ParseResult<HashSet<String>> {
-    match language {
-        Language::English => word_whitelist_txt(&resources.join("english_whitelist.txt")),
-        Language::German => word_whitelist_txt(&resources.join("german_whitelist.txt")),
-    }
-}
-
-fn word_whitelist_txt(path: &Path) -> ParseResult<HashSet<String>> {
+fn word_whitelist_txt(path: &Path, language: Language) -> ParseResult<HashSet<String>> {
+    let definition = language.definition();
     let whitelist = std::fs::read_to_string(path)?;
     let whitelist = whitelist
         .lines()
-        .map(|line| line.trim().to_ascii_lowercase().to_string())
+        .map(|line| definition.normalize_word(line.trim()))
         .collect();
     Ok(whitelist)
 }
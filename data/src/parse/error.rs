@@ -1,3 +1,4 @@
+use crate::dawg::error::DawgError;
 use wabble_core::types::language::Language;
 
 pub type ParseResult<T> = Result<T, ParseError>;
@@ -12,4 +13,8 @@ pub enum ParseError {
     Regex(#[from] regex::Error),
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("Word graph error: {0}")]
+    Dawg(#[from] DawgError),
+    #[error("No whitelist file found for language: {0:?}")]
+    NoWhitelistFile(Language),
 }